@@ -0,0 +1,211 @@
+use std::{fmt::Display, sync::atomic::AtomicBool};
+
+/// Allocations made while running a step.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default)]
+pub struct AllocStats {
+	/// Bytes allocated in total, counting reallocations with their new size.
+	pub bytes: u64,
+	/// Number of allocations and reallocations.
+	pub count: u64,
+	/// Highest number of live bytes above those live when the step started.
+	pub peak: u64,
+}
+
+/// Run the closure and count its allocations. Without the `alloc-stats`
+/// feature nothing is counted and the statistics are `None`.
+///
+/// The counters are global, so allocations of other threads running at the
+/// same time are included.
+pub fn measure<T>(f: impl FnOnce() -> T) -> (T, Option<AllocStats>) {
+	#[cfg(feature = "alloc-stats")]
+	{
+		let (value, stats) = counting::measure(f);
+		(value, Some(stats))
+	}
+	#[cfg(not(feature = "alloc-stats"))]
+	{
+		(f(), None)
+	}
+}
+
+/// Run the closure, no longer counting the allocations it makes on this
+/// thread once the flag is set. This keeps a part that was abandoned, but keeps
+/// running, out of the statistics of the steps after it.
+pub fn stop_counting_when<T>(flag: &AtomicBool, f: impl FnOnce() -> T) -> T {
+	#[cfg(feature = "alloc-stats")]
+	{
+		counting::stop_counting_when(flag, f)
+	}
+	#[cfg(not(feature = "alloc-stats"))]
+	{
+		let _ = flag;
+		f()
+	}
+}
+
+/// Bytes in binary units, e.g. `1.50 MiB`.
+pub struct Bytes(pub u64);
+
+impl Display for Bytes {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		const UNITS: [&str; 4] = ["KiB", "MiB", "GiB", "TiB"];
+		if self.0 < 1024 {
+			return write!(f, "{} B", self.0);
+		}
+		let mut value = self.0 as f64;
+		let mut unit = "B";
+		for next in UNITS {
+			if value < 1024.0 {
+				break;
+			}
+			value /= 1024.0;
+			unit = next;
+		}
+		write!(f, "{value:.2} {unit}")
+	}
+}
+
+#[cfg(feature = "alloc-stats")]
+mod counting {
+	use std::{
+		alloc::{GlobalAlloc, Layout, System},
+		cell::Cell,
+		ptr,
+		sync::atomic::{AtomicBool, AtomicU64, Ordering},
+	};
+
+	use super::AllocStats;
+
+	static BYTES: AtomicU64 = AtomicU64::new(0);
+	static COUNT: AtomicU64 = AtomicU64::new(0);
+	static LIVE: AtomicU64 = AtomicU64::new(0);
+	static PEAK: AtomicU64 = AtomicU64::new(0);
+
+	/// System allocator that counts allocations and tracks live bytes.
+	struct Counting;
+
+	#[global_allocator]
+	static ALLOCATOR: Counting = Counting;
+
+	thread_local! {
+		/// Flag that stops counting the allocations of this thread once set.
+		static STOP: Cell<*const AtomicBool> = const { Cell::new(ptr::null()) };
+	}
+
+	pub fn stop_counting_when<T>(flag: &AtomicBool, f: impl FnOnce() -> T) -> T {
+		/// Restores the previous flag, also on panics.
+		struct Restore(*const AtomicBool);
+
+		impl Drop for Restore {
+			fn drop(&mut self) {
+				STOP.with(|stop| stop.set(self.0));
+			}
+		}
+
+		let _restore = Restore(STOP.with(|stop| stop.replace(flag)));
+		f()
+	}
+
+	fn counted() -> bool {
+		let stop = STOP.with(Cell::get);
+		// SAFETY: A flag is only set while the closure borrowing it runs.
+		stop.is_null() || !unsafe { &*stop }.load(Ordering::Relaxed)
+	}
+
+	fn record_alloc(size: usize) {
+		if !counted() {
+			return;
+		}
+		BYTES.fetch_add(size as u64, Ordering::Relaxed);
+		COUNT.fetch_add(1, Ordering::Relaxed);
+		let live = LIVE.fetch_add(size as u64, Ordering::Relaxed) + size as u64;
+		PEAK.fetch_max(live, Ordering::Relaxed);
+	}
+
+	fn record_dealloc(size: usize) {
+		if counted() {
+			// Memory allocated while not counting can be freed while counting.
+			let _ = LIVE.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |live| {
+				Some(live.saturating_sub(size as u64))
+			});
+		}
+	}
+
+	// SAFETY: All calls are forwarded to the system allocator unchanged.
+	unsafe impl GlobalAlloc for Counting {
+		unsafe fn alloc(&self, layout: Layout) -> *mut u8 {
+			let ptr = System.alloc(layout);
+			if !ptr.is_null() {
+				record_alloc(layout.size());
+			}
+			ptr
+		}
+
+		unsafe fn alloc_zeroed(&self, layout: Layout) -> *mut u8 {
+			let ptr = System.alloc_zeroed(layout);
+			if !ptr.is_null() {
+				record_alloc(layout.size());
+			}
+			ptr
+		}
+
+		unsafe fn dealloc(&self, ptr: *mut u8, layout: Layout) {
+			System.dealloc(ptr, layout);
+			record_dealloc(layout.size());
+		}
+
+		unsafe fn realloc(&self, ptr: *mut u8, layout: Layout, new_size: usize) -> *mut u8 {
+			let new_ptr = System.realloc(ptr, layout, new_size);
+			if !new_ptr.is_null() {
+				record_dealloc(layout.size());
+				record_alloc(new_size);
+			}
+			new_ptr
+		}
+	}
+
+	pub fn measure<T>(f: impl FnOnce() -> T) -> (T, AllocStats) {
+		let bytes = BYTES.load(Ordering::Relaxed);
+		let count = COUNT.load(Ordering::Relaxed);
+		let live = LIVE.load(Ordering::Relaxed);
+		PEAK.store(live, Ordering::Relaxed);
+
+		let value = f();
+
+		let stats = AllocStats {
+			bytes: BYTES.load(Ordering::Relaxed) - bytes,
+			count: COUNT.load(Ordering::Relaxed) - count,
+			peak: PEAK.load(Ordering::Relaxed).saturating_sub(live),
+		};
+		(value, stats)
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn bytes() {
+		assert_eq!(Bytes(512).to_string(), "512 B");
+		assert_eq!(Bytes(1536).to_string(), "1.50 KiB");
+		assert_eq!(Bytes(3 << 30).to_string(), "3.00 GiB");
+	}
+
+	#[cfg(feature = "alloc-stats")]
+	#[test]
+	fn counting() {
+		let (vec, stats) = measure(|| std::hint::black_box(vec![0_u8; 4096]));
+		let stats = stats.expect("counting allocator is enabled");
+		assert!(stats.bytes >= 4096);
+		assert!(stats.count >= 1);
+		assert!(stats.peak >= 4096);
+		drop(vec);
+
+		let stop = AtomicBool::new(true);
+		let (vec, stats) =
+			measure(|| stop_counting_when(&stop, || std::hint::black_box(vec![0_u8; 1 << 20])));
+		assert!(stats.expect("counting allocator is enabled").bytes < 1 << 20);
+		drop(vec);
+	}
+}
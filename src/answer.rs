@@ -0,0 +1,210 @@
+use std::{cmp::Ordering, convert::Infallible, fmt::Display, str::FromStr};
+
+/// Answer to a puzzle part.
+///
+/// Integers are compared by value regardless of the variant, so
+/// `Int(5) == BigInt(5) == BigUint(5)`. The `From` implementations pick the
+/// smallest fitting variant.
+#[derive(Debug, Clone)]
+pub enum Answer {
+	/// Integer fitting into 64 bits.
+	Int(i64),
+	/// Integer too big for `Int`.
+	BigInt(i128),
+	/// Integer too big for `BigInt`.
+	BigUint(u128),
+	/// Single line of text.
+	Text(String),
+	/// Multiple lines of text, e.g. ASCII art.
+	MultiLine(Vec<String>),
+}
+
+/// Representation the comparisons are based on.
+#[derive(PartialEq, Eq, PartialOrd, Ord, Hash)]
+enum Key<'a> {
+	Number(i128),
+	/// Numbers above `i128::MAX`, greater than any `Number`.
+	BigNumber(u128),
+	Text(&'a str),
+	MultiLine(&'a [String]),
+}
+
+impl Answer {
+	/// Multi-line answer from text with `\n` line separators.
+	pub fn multi_line(text: &str) -> Self {
+		Self::MultiLine(text.lines().map(str::to_owned).collect())
+	}
+
+	/// Name of the kind of answer, used in machine-readable output.
+	pub fn kind(&self) -> &'static str {
+		match self {
+			Self::Int(_) => "int",
+			Self::BigInt(_) => "big_int",
+			Self::BigUint(_) => "big_uint",
+			Self::Text(_) => "text",
+			Self::MultiLine(_) => "multi_line",
+		}
+	}
+
+	fn key(&self) -> Key<'_> {
+		match self {
+			Self::Int(number) => Key::Number((*number).into()),
+			Self::BigInt(number) => Key::Number(*number),
+			Self::BigUint(number) => {
+				i128::try_from(*number).map_or(Key::BigNumber(*number), Key::Number)
+			}
+			Self::Text(text) => Key::Text(text),
+			Self::MultiLine(lines) => Key::MultiLine(lines),
+		}
+	}
+}
+
+impl PartialEq for Answer {
+	fn eq(&self, other: &Self) -> bool {
+		self.key() == other.key()
+	}
+}
+
+impl Eq for Answer {}
+
+impl PartialOrd for Answer {
+	fn partial_cmp(&self, other: &Self) -> Option<Ordering> {
+		Some(self.cmp(other))
+	}
+}
+
+impl Ord for Answer {
+	fn cmp(&self, other: &Self) -> Ordering {
+		self.key().cmp(&other.key())
+	}
+}
+
+impl std::hash::Hash for Answer {
+	fn hash<H: std::hash::Hasher>(&self, state: &mut H) {
+		self.key().hash(state);
+	}
+}
+
+impl Display for Answer {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Int(number) => number.fmt(f),
+			Self::BigInt(number) => number.fmt(f),
+			Self::BigUint(number) => number.fmt(f),
+			Self::Text(text) => f.write_str(text),
+			Self::MultiLine(lines) => f.write_str(&lines.join("\n")),
+		}
+	}
+}
+
+/// Parses integers as numbers, text containing newlines as multi-line and
+/// everything else as text.
+impl FromStr for Answer {
+	type Err = Infallible;
+
+	fn from_str(s: &str) -> Result<Self, Self::Err> {
+		if let Ok(number) = s.parse::<i128>() {
+			Ok(number.into())
+		} else if let Ok(number) = s.parse::<u128>() {
+			Ok(number.into())
+		} else if s.contains('\n') {
+			Ok(Self::multi_line(s))
+		} else {
+			Ok(Self::Text(s.to_owned()))
+		}
+	}
+}
+
+macro_rules! from_integers {
+	($($ty: ty),*) => {
+		$(
+			impl From<$ty> for Answer {
+				fn from(number: $ty) -> Self {
+					i128::from(number).into()
+				}
+			}
+		)*
+	};
+}
+
+from_integers!(u8, u16, u32, u64, i8, i16, i32, i64);
+
+impl From<i128> for Answer {
+	fn from(number: i128) -> Self {
+		i64::try_from(number).map_or(Self::BigInt(number), Self::Int)
+	}
+}
+
+impl From<u128> for Answer {
+	fn from(number: u128) -> Self {
+		i128::try_from(number).map_or(Self::BigUint(number), Self::from)
+	}
+}
+
+impl From<usize> for Answer {
+	fn from(number: usize) -> Self {
+		(number as u128).into()
+	}
+}
+
+impl From<isize> for Answer {
+	fn from(number: isize) -> Self {
+		(number as i128).into()
+	}
+}
+
+impl From<String> for Answer {
+	fn from(text: String) -> Self {
+		if text.contains('\n') {
+			Self::multi_line(&text)
+		} else {
+			Self::Text(text)
+		}
+	}
+}
+
+impl From<&str> for Answer {
+	fn from(text: &str) -> Self {
+		text.to_owned().into()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn conversions() {
+		assert_eq!(Answer::from(5_u8), Answer::Int(5));
+		assert!(matches!(Answer::from(u64::MAX), Answer::BigInt(_)));
+		assert_eq!(Answer::from(u128::MAX), Answer::BigUint(u128::MAX));
+		assert_eq!(Answer::from(u128::MAX).kind(), "big_uint");
+		assert!(matches!(Answer::from("abc"), Answer::Text(_)));
+		assert_eq!(Answer::from("ab\ncd"), Answer::MultiLine(vec!["ab".into(), "cd".into()]));
+
+		assert_eq!("42".parse::<Answer>(), Ok(Answer::Int(42)));
+		assert_eq!("-42".parse::<Answer>(), Ok(Answer::Int(-42)));
+		assert_eq!("18446744073709551615".parse::<Answer>(), Ok(Answer::BigInt(u64::MAX.into())));
+		let max = u128::MAX.to_string();
+		assert_eq!(max.parse::<Answer>(), Ok(Answer::BigUint(u128::MAX)));
+		assert_eq!("4 2".parse::<Answer>(), Ok(Answer::Text("4 2".into())));
+	}
+
+	#[test]
+	fn comparison() {
+		assert_eq!(Answer::Int(5), Answer::BigInt(5));
+		assert_ne!(Answer::Int(5), Answer::Text("5".into()));
+		assert!(Answer::Int(5) < Answer::BigInt(6));
+		assert_eq!(Answer::BigUint(5), Answer::Int(5));
+		assert!(Answer::BigInt(i128::MAX) < Answer::BigUint(i128::MAX as u128 + 1));
+		assert!(Answer::BigUint(u128::MAX) < Answer::Text("0".into()));
+	}
+
+	#[test]
+	fn display() {
+		let answer = Answer::multi_line("#..#\n####");
+		assert_eq!(answer.to_string(), "#..#\n####");
+		assert_eq!(answer.to_string().parse::<Answer>(), Ok(answer));
+		assert_eq!(Answer::BigInt(-1 << 100).to_string(), "-1267650600228229401496703205376");
+	}
+}
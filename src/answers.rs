@@ -0,0 +1,223 @@
+use std::{collections::BTreeMap, fmt::Write, io::ErrorKind, path::Path};
+
+use anyhow::{Context, Result};
+use clap::ValueEnum;
+
+use crate::{
+	answer::Answer,
+	report::{DayReport, Outcome},
+	runner::Part,
+};
+
+/// Default location of the answers file, next to the inputs directory.
+pub const DEFAULT_PATH: &str = "./answers.txt";
+
+/// Expected answers per year, day and part.
+///
+/// Stored as one `<year>.<day>.<part>: <answer>` line per answer, with
+/// newlines and backslashes in answers escaped. Empty lines and lines starting
+/// with `#` are ignored.
+#[derive(Debug, Default, PartialEq, Eq)]
+pub struct Answers(BTreeMap<(u16, u8, Part), Answer>);
+
+impl Answers {
+	/// Load the answers file, an absent file has no answers.
+	pub fn load(path: &Path) -> Result<Self> {
+		match std::fs::read_to_string(path) {
+			Ok(content) => {
+				Self::parse(&content).context(format!("Invalid answers file `{}`", path.display()))
+			}
+			Err(err) if err.kind() == ErrorKind::NotFound => Ok(Self::default()),
+			Err(err) => Err(err).context(format!("Could not read file `{}`", path.display())),
+		}
+	}
+
+	pub fn save(&self, path: &Path) -> Result<()> {
+		std::fs::write(path, self.to_string())
+			.context(format!("Could not write file `{}`", path.display()))
+	}
+
+	fn parse(content: &str) -> Result<Self> {
+		let mut answers = BTreeMap::new();
+		for (i, line) in content.lines().enumerate() {
+			let line = line.trim();
+			if line.is_empty() || line.starts_with('#') {
+				continue;
+			}
+
+			let parse_line = || {
+				let (key, answer) = line.split_once(':').context("Missing `:`")?;
+				let [year, day, part] = key
+					.split('.')
+					.collect::<Vec<_>>()
+					.try_into()
+					.map_err(|_| anyhow::anyhow!("Key is not `<year>.<day>.<part>`"))?;
+				let year = year.trim().parse::<u16>()?;
+				let day = day.trim().parse::<u8>()?;
+				let part = Part::from_str(part.trim(), false).map_err(anyhow::Error::msg)?;
+				let Ok(answer) = unescape(answer.trim()).parse::<Answer>();
+				Ok::<_, anyhow::Error>(((year, day, part), answer))
+			};
+			let (key, answer) = parse_line().context(format!("Line {}: `{line}`", i + 1))?;
+			answers.insert(key, answer);
+		}
+		Ok(Self(answers))
+	}
+
+	pub fn get(&self, year: u16, day: u8, part: Part) -> Option<&Answer> {
+		self.0.get(&(year, day, part))
+	}
+
+	pub fn insert(&mut self, year: u16, day: u8, part: Part, answer: Answer) {
+		self.0.insert((year, day, part), answer);
+	}
+
+	pub fn verdict(&self, year: u16, day: u8, part: Part, answer: &Answer) -> Verdict {
+		match self.get(year, day, part) {
+			None => Verdict::Missing,
+			Some(expected) if expected == answer => Verdict::Pass,
+			Some(expected) => Verdict::Fail { expected: expected.clone() },
+		}
+	}
+
+	/// Compare the answers of the reports with the expected ones. Returns one
+	/// line per part (or skipped day) and the number of failed parts, which
+	/// includes parts that returned an error.
+	pub fn check(&self, reports: &[DayReport]) -> (String, usize) {
+		let mut listing = String::new();
+		let (mut passed, mut failed, mut missing) = (0, 0, 0);
+		for report in reports {
+			match &report.outcome {
+				Outcome::Skipped(reason) => {
+					writeln!(listing, "Day {}: skipped ({reason})", report.day)
+						.expect("writing to String");
+					continue;
+				}
+				Outcome::ParseFailed { error, .. } => {
+					failed += 1;
+					writeln!(listing, "Day {}: FAIL (parse error: {error:#})", report.day)
+						.expect("writing to String");
+					continue;
+				}
+				Outcome::Solved { .. } => {}
+			}
+
+			for (part, part_report) in report.parts() {
+				let verdict = match &part_report.answer {
+					Ok(answer) => match self.verdict(report.year, report.day, part, answer) {
+						Verdict::Pass => {
+							passed += 1;
+							"pass".to_owned()
+						}
+						Verdict::Fail { expected } => {
+							failed += 1;
+							format!("FAIL (expected {expected}, got {answer})")
+						}
+						Verdict::Missing => {
+							missing += 1;
+							format!("missing (got {answer})")
+						}
+					},
+					Err(err) => {
+						failed += 1;
+						format!("FAIL (error: {err:#})")
+					}
+				};
+				writeln!(listing, "Day {} part {part}: {verdict}", report.day)
+					.expect("writing to String");
+			}
+		}
+		writeln!(listing, "\n{passed} passed, {failed} failed, {missing} missing")
+			.expect("writing to String");
+		(listing, failed)
+	}
+}
+
+impl std::fmt::Display for Answers {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		writeln!(f, "# Expected answers as `<year>.<day>.<part>: <answer>`.")?;
+		for ((year, day, part), answer) in &self.0 {
+			writeln!(f, "{year}.{day}.{part}: {}", escape(&answer.to_string()))?;
+		}
+		Ok(())
+	}
+}
+
+fn escape(answer: &str) -> String {
+	answer.replace('\\', r"\\").replace('\n', r"\n")
+}
+
+fn unescape(answer: &str) -> String {
+	let mut unescaped = String::with_capacity(answer.len());
+	let mut chars = answer.chars();
+	while let Some(c) = chars.next() {
+		match (c, chars.clone().next()) {
+			('\\', Some('n')) => {
+				unescaped.push('\n');
+				chars.next();
+			}
+			('\\', Some('\\')) => {
+				unescaped.push('\\');
+				chars.next();
+			}
+			_ => unescaped.push(c),
+		}
+	}
+	unescaped
+}
+
+/// Verdict of comparing a computed answer to the expected one.
+#[derive(Debug, PartialEq, Eq)]
+pub enum Verdict {
+	Pass,
+	Fail {
+		expected: Answer,
+	},
+	/// There is no expected answer.
+	Missing,
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn roundtrip() -> Result<()> {
+		let mut answers = Answers::default();
+		answers.insert(2023, 1, Part::One, Answer::Int(142));
+		answers.insert(2023, 10, Part::Two, Answer::multi_line("a\\b\nc"));
+		answers.insert(2023, 12, Part::One, Answer::BigInt(1 << 100));
+		answers.insert(2022, 1, Part::One, Answer::Int(7));
+
+		let content = answers.to_string();
+		assert!(content.contains("\n2022.1.1: 7\n2023.1.1: 142\n"));
+		assert!(content.contains(r"2023.10.2: a\\b\nc"));
+		assert_eq!(Answers::parse(&content)?, answers);
+
+		Ok(())
+	}
+
+	#[test]
+	fn verdicts() -> Result<()> {
+		let answers = Answers::parse("# comment\n\n2023.5.1: 35\n")?;
+		assert_eq!(answers.verdict(2023, 5, Part::One, &Answer::Int(35)), Verdict::Pass);
+		assert_eq!(answers.verdict(2023, 5, Part::One, &Answer::BigInt(35)), Verdict::Pass);
+		assert_eq!(
+			answers.verdict(2023, 5, Part::One, &Answer::Int(36)),
+			Verdict::Fail { expected: Answer::Int(35) }
+		);
+		assert_eq!(
+			answers.verdict(2023, 5, Part::One, &Answer::Text("35 ".to_owned())),
+			Verdict::Fail { expected: Answer::Int(35) }
+		);
+		assert_eq!(answers.verdict(2023, 5, Part::Two, &Answer::Int(46)), Verdict::Missing);
+
+		assert_eq!(answers.verdict(2022, 5, Part::One, &Answer::Int(35)), Verdict::Missing);
+
+		assert!(Answers::parse("2023.5.3: 35").is_err());
+		assert!(Answers::parse("5.1: 35").is_err());
+		assert!(Answers::parse("5 35").is_err());
+
+		Ok(())
+	}
+}
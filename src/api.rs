@@ -0,0 +1,68 @@
+use std::fmt::Display;
+
+use crate::{
+	days::{self, Puzzle, RunContext},
+	parse::ParseError,
+	runner::Part,
+	Answer,
+};
+
+/// Error solving a puzzle through the library API.
+#[derive(Debug)]
+pub enum SolveError {
+	/// There is no solution for the day.
+	NotImplemented { year: u16, day: u8 },
+	/// The day's parser rejected the input, also when a day that parses in its
+	/// parts only finds out while solving.
+	Parse { year: u16, day: u8, error: anyhow::Error },
+	/// The part returned an error.
+	Solve { year: u16, day: u8, part: Part, error: anyhow::Error },
+}
+
+impl Display for SolveError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::NotImplemented { year, day } => {
+				write!(f, "Day {day} of {year} is not implemented")
+			}
+			Self::Parse { year, day, .. } => {
+				write!(f, "Could not parse the input of day {day} of {year}")
+			}
+			Self::Solve { year, day, part, .. } => {
+				write!(f, "Day {day} of {year} part {part} failed")
+			}
+		}
+	}
+}
+
+impl std::error::Error for SolveError {
+	fn source(&self) -> Option<&(dyn std::error::Error + 'static)> {
+		match self {
+			Self::NotImplemented { .. } => None,
+			Self::Parse { error, .. } | Self::Solve { error, .. } => Some(error.as_ref()),
+		}
+	}
+}
+
+/// Year and day of the implemented days in ascending order.
+pub fn implemented_days() -> impl Iterator<Item = (u16, u8)> {
+	days::DAYS.keys().copied()
+}
+
+/// Solution of the given day, if it is implemented.
+pub fn puzzle(year: u16, day: u8) -> Option<&'static dyn Puzzle> {
+	days::DAYS.get(&(year, day)).map(AsRef::as_ref)
+}
+
+/// Solve one part of a day on the given input.
+pub fn solve(year: u16, day: u8, part: Part, input: &str) -> Result<Answer, SolveError> {
+	let puzzle = puzzle(year, day).ok_or(SolveError::NotImplemented { year, day })?;
+	let parsed = puzzle.parse(input).map_err(|error| SolveError::Parse { year, day, error })?;
+	puzzle.solve(part, &parsed, &RunContext::default()).map_err(|error| {
+		if error.root_cause().is::<ParseError>() {
+			SolveError::Parse { year, day, error }
+		} else {
+			SolveError::Solve { year, day, part, error }
+		}
+	})
+}
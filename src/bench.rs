@@ -0,0 +1,290 @@
+use std::{
+	fmt::Display,
+	hint::black_box,
+	num::NonZeroUsize,
+	time::{Duration, Instant},
+};
+
+use anyhow::{bail, Context, Result};
+
+use crate::{
+	crash,
+	days::{self, ParsedInput, Puzzle, RunContext},
+	report::render_table,
+	runner::{self, Part},
+	selection::DaySelection,
+};
+
+/// Options controlling how long each part is measured.
+#[derive(Debug, Clone)]
+pub struct BenchOptions {
+	/// Only benchmark this part instead of both.
+	pub part: Option<Part>,
+	/// Time to run a part before measuring it.
+	pub warmup: Duration,
+	/// Time to spend measuring a part, at least one run is always measured.
+	pub budget: Duration,
+	/// Exact number of measured runs, overriding the time budget.
+	pub iterations: Option<NonZeroUsize>,
+}
+
+/// Measured step of a day: parsing the input or solving a part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Step {
+	Parse,
+	Part(Part),
+}
+
+impl Display for Step {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match self {
+			Self::Parse => f.write_str("parse"),
+			Self::Part(part) => part.fmt(f),
+		}
+	}
+}
+
+/// Benchmark result of a single step or a skipped day.
+#[derive(Debug)]
+pub struct BenchReport {
+	pub day: u8,
+	pub outcome: BenchOutcome,
+}
+
+#[derive(Debug)]
+pub enum BenchOutcome {
+	/// The day was not run, with the reason why.
+	Skipped(String),
+	/// The step returned an error, so it was not measured.
+	Failed {
+		step: Step,
+		error: anyhow::Error,
+	},
+	Measured {
+		step: Step,
+		stats: Stats,
+	},
+}
+
+/// Statistics over the measured runs of a part.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Stats {
+	pub runs: usize,
+	pub min: Duration,
+	pub median: Duration,
+	pub mean: Duration,
+	pub p95: Duration,
+	pub std_dev: Duration,
+}
+
+impl Stats {
+	/// Compute the statistics of the samples, which must not be empty.
+	pub fn from_samples(mut samples: Vec<Duration>) -> Self {
+		assert!(!samples.is_empty(), "no samples to compute statistics of");
+		samples.sort();
+
+		let runs = samples.len();
+		let mean = samples.iter().sum::<Duration>() / runs as u32;
+		let variance = if runs > 1 {
+			samples
+				.iter()
+				.map(|sample| (sample.as_secs_f64() - mean.as_secs_f64()).powi(2))
+				.sum::<f64>()
+				/ (runs - 1) as f64
+		} else {
+			0.0
+		};
+		let median = if runs.is_multiple_of(2) {
+			(samples[runs / 2 - 1] + samples[runs / 2]) / 2
+		} else {
+			samples[runs / 2]
+		};
+		// Nearest-rank percentile.
+		let p95_rank = (runs * 95).div_ceil(100);
+
+		Self {
+			runs,
+			min: samples[0],
+			median,
+			mean,
+			p95: samples[p95_rank - 1],
+			std_dev: Duration::from_secs_f64(variance.sqrt()),
+		}
+	}
+}
+
+/// Benchmark the selected days' parts.
+pub fn bench_days(
+	year: u16,
+	selection: &DaySelection,
+	options: &BenchOptions,
+) -> Result<Vec<BenchReport>> {
+	let mut reports = Vec::new();
+	for day in runner::selected_days(year, selection) {
+		let Some(solution) = days::DAYS.get(&(year, day)) else {
+			reports.push(BenchReport {
+				day,
+				outcome: BenchOutcome::Skipped("not implemented".to_owned()),
+			});
+			continue;
+		};
+		let Some(input) = runner::fetch_input(year, day, None)? else {
+			reports.push(BenchReport {
+				day,
+				outcome: BenchOutcome::Skipped("no input file".to_owned()),
+			});
+			continue;
+		};
+
+		let solution = solution.as_ref();
+		let parsed = match crash::catch(|| solution.parse(&input)) {
+			Ok(parsed) => parsed,
+			Err(error) => {
+				let outcome = BenchOutcome::Failed { step: Step::Parse, error };
+				reports.push(BenchReport { day, outcome });
+				continue;
+			}
+		};
+		let outcome = match measure(|| solution.parse(black_box(&input)).map(drop), options) {
+			Ok(stats) => BenchOutcome::Measured { step: Step::Parse, stats },
+			Err(error) => BenchOutcome::Failed { step: Step::Parse, error },
+		};
+		reports.push(BenchReport { day, outcome });
+
+		for part in [Part::One, Part::Two] {
+			if options.part.is_some_and(|only| only != part) {
+				continue;
+			}
+			let step = Step::Part(part);
+			let outcome = match bench_part(solution, part, &parsed, options) {
+				Ok(stats) => BenchOutcome::Measured { step, stats },
+				Err(error) => BenchOutcome::Failed { step, error },
+			};
+			reports.push(BenchReport { day, outcome });
+		}
+	}
+	Ok(reports)
+}
+
+fn bench_part(
+	solution: &dyn Puzzle,
+	part: Part,
+	parsed: &ParsedInput,
+	options: &BenchOptions,
+) -> Result<Stats> {
+	let ctx = RunContext::default();
+	measure(|| solution.solve(part, black_box(parsed), &ctx).map(drop), options)
+}
+
+/// Warm up and then measure the step according to the options. A panic of the
+/// step fails it.
+fn measure(run: impl Fn() -> Result<()>, options: &BenchOptions) -> Result<Stats> {
+	let warmup = Instant::now();
+	while warmup.elapsed() < options.warmup {
+		black_box(crash::catch(&run)?);
+	}
+
+	let mut samples = Vec::new();
+	let measuring = Instant::now();
+	loop {
+		let done = match options.iterations {
+			Some(iterations) => samples.len() >= iterations.get(),
+			None => !samples.is_empty() && measuring.elapsed() >= options.budget,
+		};
+		if done {
+			break;
+		}
+
+		let time = Instant::now();
+		black_box(crash::catch(&run)?);
+		samples.push(time.elapsed());
+	}
+
+	Ok(Stats::from_samples(samples))
+}
+
+/// Render the benchmark reports as a table.
+pub fn bench_table(reports: &[BenchReport]) -> String {
+	let mut rows =
+		vec![["Day", "Step", "Runs", "Min", "Median", "Mean", "P95", "Std dev"].map(String::from)];
+	for report in reports {
+		let day = report.day.to_string();
+		let row = match &report.outcome {
+			BenchOutcome::Skipped(reason) => note_row(day, format!("skipped ({reason})")),
+			BenchOutcome::Failed { step, error } => {
+				note_row(day, format!("{step} (error: {error:#})"))
+			}
+			BenchOutcome::Measured { step, stats } => [
+				day,
+				step.to_string(),
+				stats.runs.to_string(),
+				format!("{:.2?}", stats.min),
+				format!("{:.2?}", stats.median),
+				format!("{:.2?}", stats.mean),
+				format!("{:.2?}", stats.p95),
+				format!("{:.2?}", stats.std_dev),
+			],
+		};
+		rows.push(row);
+	}
+	render_table(&rows, false)
+}
+
+/// Row with only the day and a note instead of statistics.
+fn note_row(day: String, note: String) -> [String; 8] {
+	let mut row = std::array::from_fn(|_| "-".to_owned());
+	row[0] = day;
+	row[1] = note;
+	row
+}
+
+/// Parse a duration like `500ms`, `3s` or `1.5m`.
+pub fn parse_duration(s: &str) -> Result<Duration> {
+	let s = s.trim();
+	let split = s.find(|c: char| c.is_alphabetic()).context("Missing unit (ns, us, ms, s, m)")?;
+	let (number, unit) = s.split_at(split);
+	let number = number.trim().parse::<f64>().context(format!("Invalid number `{number}`"))?;
+	let seconds = match unit {
+		"ns" => number / 1e9,
+		"us" | "µs" => number / 1e6,
+		"ms" => number / 1e3,
+		"s" => number,
+		"m" => number * 60.0,
+		_ => bail!("Invalid unit `{unit}`, expected one of ns, us, ms, s, m"),
+	};
+	Duration::try_from_secs_f64(seconds).context(format!("Invalid duration `{s}`"))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn stats() {
+		let samples = (1..=20).rev().map(Duration::from_millis).collect();
+		let stats = Stats::from_samples(samples);
+		assert_eq!(stats.runs, 20);
+		assert_eq!(stats.min, Duration::from_millis(1));
+		assert_eq!(stats.median, Duration::from_micros(10_500));
+		assert_eq!(stats.mean, Duration::from_micros(10_500));
+		assert_eq!(stats.p95, Duration::from_millis(19));
+		assert_eq!(stats.std_dev.as_micros(), 5_916);
+
+		let stats = Stats::from_samples(vec![Duration::from_millis(3)]);
+		assert_eq!(stats.median, Duration::from_millis(3));
+		assert_eq!(stats.p95, Duration::from_millis(3));
+		assert_eq!(stats.std_dev, Duration::ZERO);
+	}
+
+	#[test]
+	fn durations() -> Result<()> {
+		assert_eq!(parse_duration("500ms")?, Duration::from_millis(500));
+		assert_eq!(parse_duration("3s")?, Duration::from_secs(3));
+		assert_eq!(parse_duration("1.5m")?, Duration::from_secs(90));
+		assert_eq!(parse_duration("0s")?, Duration::ZERO);
+		assert!(parse_duration("3").is_err());
+		assert!(parse_duration("3h").is_err());
+		assert!(parse_duration("-1s").is_err());
+		Ok(())
+	}
+}
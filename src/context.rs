@@ -0,0 +1,135 @@
+use std::{
+	fmt::Display,
+	sync::{
+		atomic::{AtomicBool, Ordering},
+		Arc,
+	},
+};
+
+use anyhow::Result;
+
+/// Progress of a running part.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Progress {
+	/// `current` of `total` steps are done.
+	Fraction { current: u64, total: u64 },
+	/// Number of steps done when the total is unknown.
+	Count(u64),
+}
+
+impl Display for Progress {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match *self {
+			Self::Fraction { current, total } => {
+				let percent = current as f64 / total.max(1) as f64 * 100.0;
+				write!(f, "{current}/{total} ({percent:.1}%)")
+			}
+			Self::Count(count) => write!(f, "{count}"),
+		}
+	}
+}
+
+/// Error of a part that stopped because it was cancelled.
+#[derive(Debug)]
+pub struct Cancelled;
+
+impl Display for Cancelled {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.write_str("cancelled")
+	}
+}
+
+impl std::error::Error for Cancelled {}
+
+type Callback = Box<dyn Fn(Progress) + Send + Sync>;
+
+/// Context passed to a running part, to report progress and notice
+/// cancellation requests. The default context ignores progress and is never
+/// cancelled.
+#[derive(Default)]
+pub struct RunContext {
+	progress: Option<Callback>,
+	cancelled: Arc<AtomicBool>,
+}
+
+impl RunContext {
+	/// Context passing the progress to the callback.
+	pub fn with_progress(callback: impl Fn(Progress) + Send + Sync + 'static) -> Self {
+		Self { progress: Some(Box::new(callback)), ..Self::default() }
+	}
+
+	/// Flag that cancels the part when set, e.g. from another thread.
+	pub fn cancel_flag(&self) -> Arc<AtomicBool> {
+		Arc::clone(&self.cancelled)
+	}
+
+	pub fn cancel(&self) {
+		self.cancelled.store(true, Ordering::Relaxed);
+	}
+
+	pub fn is_cancelled(&self) -> bool {
+		self.cancelled.load(Ordering::Relaxed)
+	}
+
+	/// Fail with [`Cancelled`] if the part was cancelled, for use with `?` in
+	/// long-running loops.
+	pub fn check_cancelled(&self) -> Result<()> {
+		if self.is_cancelled() {
+			Err(Cancelled.into())
+		} else {
+			Ok(())
+		}
+	}
+
+	/// Report that `current` of `total` steps are done.
+	pub fn progress(&self, current: u64, total: u64) {
+		self.report(Progress::Fraction { current, total });
+	}
+
+	/// Report that `count` steps are done when the total is unknown.
+	pub fn count(&self, count: u64) {
+		self.report(Progress::Count(count));
+	}
+
+	fn report(&self, progress: Progress) {
+		if let Some(callback) = &self.progress {
+			callback(progress);
+		}
+	}
+}
+
+impl std::fmt::Debug for RunContext {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		f.debug_struct("RunContext")
+			.field("progress", &self.progress.is_some())
+			.field("cancelled", &self.is_cancelled())
+			.finish()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use std::sync::Mutex;
+
+	use super::*;
+
+	#[test]
+	fn context() {
+		let reported = Arc::new(Mutex::new(Vec::new()));
+		let ctx = RunContext::with_progress({
+			let reported = Arc::clone(&reported);
+			move |progress| reported.lock().expect("lock progress").push(progress)
+		});
+		ctx.progress(1, 4);
+		ctx.count(7);
+		assert_eq!(
+			*reported.lock().expect("lock progress"),
+			[Progress::Fraction { current: 1, total: 4 }, Progress::Count(7)]
+		);
+		assert_eq!(Progress::Fraction { current: 1, total: 4 }.to_string(), "1/4 (25.0%)");
+
+		assert!(ctx.check_cancelled().is_ok());
+		ctx.cancel_flag().store(true, Ordering::Relaxed);
+		assert!(ctx.check_cancelled().is_err_and(|err| err.is::<Cancelled>()));
+	}
+}
@@ -0,0 +1,106 @@
+use std::{
+	any::Any,
+	cell::{Cell, RefCell},
+	fmt::Display,
+	panic::{self, AssertUnwindSafe},
+	sync::Once,
+};
+
+use anyhow::Result;
+
+/// Error of a step that panicked.
+#[derive(Debug)]
+pub struct Crashed {
+	pub message: String,
+	/// Source location of the panic, like `src/days/year_2023/day_3.rs:94:42`.
+	pub location: Option<String>,
+}
+
+impl Display for Crashed {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.location {
+			Some(location) => write!(f, "crashed at {location}: {}", self.message),
+			None => write!(f, "crashed: {}", self.message),
+		}
+	}
+}
+
+impl std::error::Error for Crashed {}
+
+thread_local! {
+	/// Number of [`catch`] calls running on this thread, while its panics are
+	/// not printed.
+	static CATCHING: Cell<usize> = const { Cell::new(0) };
+	/// Message and location of the last panic caught on this thread.
+	static PANIC: RefCell<Option<(String, Option<String>)>> = const { RefCell::new(None) };
+}
+
+/// Run the closure, turning a panic into a [`Crashed`] error instead of
+/// unwinding further. Panics of this thread are not printed while catching,
+/// their location is part of the error. Panics of other threads, e.g. of
+/// rayon, are printed by the previous hook and caught without a location.
+pub fn catch<T>(f: impl FnOnce() -> Result<T>) -> Result<T> {
+	static HOOK: Once = Once::new();
+	HOOK.call_once(|| {
+		let previous_hook = panic::take_hook();
+		panic::set_hook(Box::new(move |info| {
+			if CATCHING.with(Cell::get) == 0 {
+				previous_hook(info);
+			} else {
+				let location = info.location().map(ToString::to_string);
+				PANIC
+					.with(|panic| panic.replace(Some((payload_message(info.payload()), location))));
+			}
+		}));
+	});
+
+	CATCHING.with(|catching| catching.set(catching.get() + 1));
+	let result = panic::catch_unwind(AssertUnwindSafe(f));
+	CATCHING.with(|catching| catching.set(catching.get() - 1));
+	result.unwrap_or_else(|payload| {
+		let message = payload_message(payload.as_ref());
+		// A panic resumed from another thread was printed there, and is not
+		// the one recorded here, if any.
+		let location = PANIC
+			.with(RefCell::take)
+			.filter(|(recorded, _)| *recorded == message)
+			.and_then(|(_, location)| location);
+		Err(Crashed { message, location }.into())
+	})
+}
+
+fn payload_message(payload: &(dyn Any + Send)) -> String {
+	if let Some(message) = payload.downcast_ref::<&str>() {
+		(*message).to_owned()
+	} else if let Some(message) = payload.downcast_ref::<String>() {
+		message.clone()
+	} else {
+		"unknown panic payload".to_owned()
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn catch_panics() {
+		assert_eq!(catch(|| Ok(1)).ok(), Some(1));
+
+		let line = line!() + 1;
+		let error = catch::<()>(|| panic!("day {} exploded", 3)).expect_err("panicked");
+		let crashed = error.downcast_ref::<Crashed>().expect("crashed error");
+		assert_eq!(crashed.message, "day 3 exploded");
+		let location = crashed.location.as_deref().expect("panic location");
+		assert!(location.starts_with(&format!("{}:{line}:", file!())), "{location}");
+		assert_eq!(error.to_string(), format!("crashed at {location}: day 3 exploded"));
+
+		let error = catch::<()>(|| {
+			let thread = std::thread::spawn(|| panic!("elsewhere"));
+			panic::resume_unwind(thread.join().expect_err("panicked"))
+		})
+		.expect_err("panicked");
+		let crashed = error.downcast_ref::<Crashed>().expect("crashed error");
+		assert_eq!((crashed.message.as_str(), &crashed.location), ("elsewhere", &None));
+	}
+}
@@ -0,0 +1,79 @@
+/// Cycle of the states produced by repeatedly stepping from an initial state.
+#[derive(Debug, Clone)]
+pub struct Cycle<S> {
+	/// Number of steps to the first state that repeats.
+	pub start: usize,
+	/// Number of steps until a state repeats.
+	pub length: usize,
+	initial: S,
+}
+
+impl<S: Clone> Cycle<S> {
+	/// Smallest number of steps reaching the same state as `n` steps.
+	pub fn reduce(&self, n: usize) -> usize {
+		if n < self.start {
+			n
+		} else {
+			self.start + (n - self.start) % self.length
+		}
+	}
+
+	/// State after `n` steps, taking less than `start + length` steps.
+	pub fn state_at(&self, n: usize, mut step: impl FnMut(&S) -> S) -> S {
+		let mut state = self.initial.clone();
+		for _ in 0..self.reduce(n) {
+			state = step(&state);
+		}
+		state
+	}
+}
+
+/// Find the cycle with Brent's algorithm, keeping only two states at a time.
+/// Never returns if the states do not repeat.
+pub fn find_cycle<S: Clone + Eq>(initial: S, mut step: impl FnMut(&S) -> S) -> Cycle<S> {
+	let mut power = 1;
+	let mut length = 1;
+	let mut tortoise = initial.clone();
+	let mut hare = step(&initial);
+	while tortoise != hare {
+		if power == length {
+			tortoise = hare.clone();
+			power *= 2;
+			length = 0;
+		}
+		hare = step(&hare);
+		length += 1;
+	}
+
+	let mut tortoise = initial.clone();
+	let mut hare = initial.clone();
+	for _ in 0..length {
+		hare = step(&hare);
+	}
+	let mut start = 0;
+	while tortoise != hare {
+		tortoise = step(&tortoise);
+		hare = step(&hare);
+		start += 1;
+	}
+	Cycle { start, length, initial }
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn cycles() {
+		// 3 → 10 → 5 → 16 → 8 → 4 → 2 → 1 → 4
+		let collatz = |&n: &u64| if n % 2 == 0 { n / 2 } else { 3 * n + 1 };
+		let cycle = find_cycle(3, collatz);
+		assert_eq!((cycle.start, cycle.length), (5, 3));
+		assert_eq!(cycle.reduce(4), 4);
+		assert_eq!(cycle.reduce(1_000_000_000), 7);
+		assert_eq!(cycle.state_at(1_000_000_000, collatz), 1);
+
+		let cycle = find_cycle(0, |&n| (n + 1) % 7);
+		assert_eq!((cycle.start, cycle.length), (0, 7));
+	}
+}
@@ -1,13 +1,128 @@
-use std::collections::BTreeMap;
+use std::{any::Any, collections::BTreeMap};
 
-use anyhow::Result;
+use anyhow::{Context, Result};
 use once_cell::sync::Lazy;
 
+pub use crate::{answer::Answer, context::RunContext};
+use crate::{
+	input::{self, SourceMap},
+	parse,
+	runner::Part,
+};
+
+/// Day solving both parts from the raw input.
 pub trait AocDay: Send + Sync {
-	fn part1(&self, input: &str) -> Result<String>;
-	fn part2(&self, input: &str) -> Result<String>;
+	/// Whether the input must be plain ASCII, checked before solving.
+	const ASCII_ONLY: bool = false;
+
+	fn part1(&self, input: &str) -> Result<Answer>;
+	fn part2(&self, input: &str) -> Result<Answer>;
+}
+
+/// Day with a separate parse step, so the input is parsed once and shared by
+/// both parts. Long-running parts can report progress and stop on
+/// cancellation through the context.
+pub trait Solution: Send + Sync {
+	type Parsed: Send + Sync + 'static;
+
+	/// Whether the input must be plain ASCII, checked before parsing.
+	const ASCII_ONLY: bool = false;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed>;
+	fn part1(&self, parsed: &Self::Parsed, ctx: &RunContext) -> Result<Answer>;
+	fn part2(&self, parsed: &Self::Parsed, ctx: &RunContext) -> Result<Answer>;
 }
 
+/// Adapter for days that parse in each part: parsing just keeps the input and
+/// parse errors of the parts are located in it.
+impl<T: AocDay> Solution for T {
+	type Parsed = String;
+
+	const ASCII_ONLY: bool = <T as AocDay>::ASCII_ONLY;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Ok(input.to_owned())
+	}
+
+	fn part1(&self, input: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		AocDay::part1(self, input).map_err(|err| parse::locate(err, input))
+	}
+
+	fn part2(&self, input: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		AocDay::part2(self, input).map_err(|err| parse::locate(err, input))
+	}
+}
+
+/// Parsed input of a [`Puzzle`], with the concrete type erased.
+pub struct ParsedInput {
+	parsed: Box<dyn Any + Send + Sync>,
+	source_map: SourceMap,
+}
+
+impl ParsedInput {
+	fn downcast<T: 'static>(&self) -> Result<&T> {
+		self.parsed.downcast_ref().context("Parsed input belongs to a different day")
+	}
+}
+
+/// Object-safe form of a [`Solution`], as stored in the registry. Parsing
+/// normalizes the input first, see [`input::normalize`], and locates a
+/// [`parse::ParseError`] in the raw input.
+pub trait Puzzle: Send + Sync {
+	fn parse(&self, input: &str) -> Result<ParsedInput>;
+	fn part1(&self, parsed: &ParsedInput, ctx: &RunContext) -> Result<Answer>;
+	fn part2(&self, parsed: &ParsedInput, ctx: &RunContext) -> Result<Answer>;
+
+	/// Solve the given part.
+	fn solve(&self, part: Part, parsed: &ParsedInput, ctx: &RunContext) -> Result<Answer> {
+		match part {
+			Part::One => self.part1(parsed, ctx),
+			Part::Two => self.part2(parsed, ctx),
+		}
+	}
+}
+
+impl<T: Solution> Puzzle for T {
+	fn parse(&self, input: &str) -> Result<ParsedInput> {
+		let (input, source_map) = input::prepare(input, T::ASCII_ONLY)?;
+		let parsed = Solution::parse(self, &input)
+			.map_err(|err| source_map.relocate(parse::locate(err, &input)))?;
+		Ok(ParsedInput { parsed: Box::new(parsed), source_map })
+	}
+
+	fn part1(&self, parsed: &ParsedInput, ctx: &RunContext) -> Result<Answer> {
+		Solution::part1(self, parsed.downcast()?, ctx)
+			.map_err(|err| parsed.source_map.relocate(err))
+	}
+
+	fn part2(&self, parsed: &ParsedInput, ctx: &RunContext) -> Result<Answer> {
+		Solution::part2(self, parsed.downcast()?, ctx)
+			.map_err(|err| parsed.source_map.relocate(err))
+	}
+}
+
+/// Helpers for the tests of the days.
+#[cfg(test)]
+pub mod testing {
+	use anyhow::Result;
+
+	use super::{Answer, Puzzle, RunContext};
+	pub use crate::runner::Part;
+
+	/// Normalize an example input, see
+	/// [`input::normalize`](crate::input::normalize).
+	pub fn normalize(input: &str) -> String {
+		crate::input::normalize(input).0
+	}
+
+	/// Solve a part on an example input, normalized like any other input.
+	pub fn solve(day: &dyn Puzzle, part: Part, input: &str) -> Result<Answer> {
+		let parsed = day.parse(input)?;
+		day.solve(part, &parsed, &RunContext::default())
+	}
+}
+
+/// Declare the day modules of a year module and collect their solutions.
 macro_rules! implemented_days {
 	($($days: literal),*) => {
 		paste::paste! {
@@ -15,10 +130,29 @@ macro_rules! implemented_days {
 				mod [< day_ $days >];
 			)*
 
-			pub static DAYS: Lazy<BTreeMap<u8, Box<dyn AocDay>>> = Lazy::new(|| {
-				let mut map = BTreeMap::<u8, Box<dyn AocDay>>::new();
+			/// Solutions of this year by day.
+			pub fn days() -> Vec<(u8, Box<dyn super::Puzzle>)> {
+				vec![$(($days, Box::new(self::[< day_ $days >]::Day))),*]
+			}
+		}
+	};
+}
+
+/// Declare the year modules and build the registry from their days.
+macro_rules! implemented_years {
+	($($years: literal),*) => {
+		paste::paste! {
+			$(
+				mod [< year_ $years >];
+			)*
+
+			/// Solutions by year and day.
+			pub static DAYS: Lazy<BTreeMap<(u16, u8), Box<dyn Puzzle>>> = Lazy::new(|| {
+				let mut map = BTreeMap::<(u16, u8), Box<dyn Puzzle>>::new();
 				$(
-					map.insert($days, Box::new(self::[< day_ $days >]::Day));
+					for (day, solution) in self::[< year_ $years >]::days() {
+						map.insert(($years, day), solution);
+					}
 				)*
 				map
 			});
@@ -26,4 +160,4 @@ macro_rules! implemented_days {
 	};
 }
 
-implemented_days!(1, 2, 3, 4, 5, 6, 7, 8, 9, 10, 11, 12, 13, 14, 15, 16, 17, 18, 19, 20);
+implemented_years!(2023);
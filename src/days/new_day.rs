@@ -2,41 +2,40 @@ use std::str::FromStr;
 
 use anyhow::{Context, Result};
 
-use super::AocDay;
+use super::{Answer, AocDay};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
-		Ok("unimplemented".to_string())
+	fn part1(&self, input: &str) -> Result<Answer> {
+		Ok("unimplemented".into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
-		Ok("unimplemented".to_string())
+	fn part2(&self, input: &str) -> Result<Answer> {
+		Ok("unimplemented".into())
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
-		Time:      7  15   30
-		Distance:  9  40  200
 		"#;
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "288");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(0));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "71503");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(0));
 
 		Ok(())
 	}
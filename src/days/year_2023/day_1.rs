@@ -1,29 +1,30 @@
 use ahash::AHashMap;
-use anyhow::{Context, Result};
+use anyhow::Result;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::OrParseError;
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let mut result = 0;
 
-		for line in input.lines().map(str::trim).filter(|s| !s.is_empty()) {
+		for line in input.lines().filter(|line| !line.is_empty()) {
 			let digit_start =
-				line.chars().find(|c| c.is_numeric()).context("Could not find start digit")?;
+				line.chars().find(|c| c.is_numeric()).or_parse_error(line, "No digit on the line")?;
 			let digit_end =
-				line.chars().rfind(|c| c.is_numeric()).context("Could not find end digit")?;
+				line.chars().rfind(|c| c.is_numeric()).or_parse_error(line, "No digit on the line")?;
 			let full_digit = format!("{digit_start}{digit_end}").parse::<u64>()?;
 			result += full_digit;
 		}
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		static FIRST_NUMBER_REGEX: Lazy<Regex> = Lazy::new(|| {
 			Regex::new(r"^.*?([1-9]|one|two|three|four|five|six|seven|eight|nine).*$")
 				.expect("creating regex")
@@ -46,38 +47,40 @@ impl AocDay for Day {
 
 		let mut result = 0;
 
-		for line in input.lines().map(str::trim).filter(|s| !s.is_empty()) {
+		for line in input.lines().filter(|line| !line.is_empty()) {
 			let captures =
-				FIRST_NUMBER_REGEX.captures(line).context("Applying first number regex")?;
+				FIRST_NUMBER_REGEX.captures(line).or_parse_error(line, "No digit on the line")?;
 			let digit_start = map.get(&captures[1]).copied().unwrap_or(&captures[1]);
 
 			let captures =
-				LAST_NUMBER_REGEX.captures(line).context("Applying first number regex")?;
+				LAST_NUMBER_REGEX.captures(line).or_parse_error(line, "No digit on the line")?;
 			let digit_end = map.get(&captures[1]).copied().unwrap_or(&captures[1]);
 
 			let full_digit = format!("{digit_start}{digit_end}").parse::<u64>()?;
 			result += full_digit;
 		}
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	#[test]
 	fn part1() -> Result<()> {
 		let input = r#"
 		1abc2
 		pqr3stu8vwx
+
 		a1b2c3d4e5f
 		treb7uchet
 		"#;
 
-		let result = Day.part1(input)?;
-		assert_eq!(result.as_str(), "142");
+		let result = solve(&Day, Part::One, input)?;
+		assert_eq!(result, Answer::Int(142));
 
 		Ok(())
 	}
@@ -94,8 +97,8 @@ mod tests {
 		7pqrstsixteen
 		"#;
 
-		let result = Day.part2(input)?;
-		assert_eq!(result.as_str(), "281");
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(281));
 
 		Ok(())
 	}
@@ -0,0 +1,274 @@
+use std::str::FromStr;
+
+use ahash::AHashSet;
+use anyhow::{bail, Context, Result};
+
+use super::{Answer, RunContext, Solution};
+use crate::{
+	geometry::{Direction, Position},
+	grid::Grid,
+	parse::{OrParseError, ParseError},
+};
+
+pub struct Day;
+
+impl Solution for Day {
+	type Parsed = Sketch;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Sketch::from_str(input)
+	}
+
+	fn part1(&self, sketch: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let farthest = sketch.pipe_loop.len() / 2;
+		Ok(farthest.into())
+	}
+
+	fn part2(&self, sketch: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let l: AHashSet<Position> = sketch.pipe_loop.iter().copied().collect();
+		let mut enclosed = 0;
+		for position in sketch.grid.positions() {
+			if sketch.is_enclosed(position, &l)? {
+				enclosed += 1;
+			}
+		}
+
+		Ok(enclosed.into())
+	}
+}
+
+#[derive(Debug, Default)]
+struct Directions {
+	up: usize,
+	down: usize,
+	left: usize,
+	right: usize,
+}
+
+impl Directions {
+	pub fn is_vertical(&self) -> bool {
+		self.up % 2 == 1 && self.down % 2 == 1
+	}
+
+	pub fn is_horizontal(&self) -> bool {
+		self.left % 2 == 1 && self.right % 2 == 1
+	}
+}
+
+impl TryFrom<char> for Directions {
+	type Error = anyhow::Error;
+
+	fn try_from(c: char) -> Result<Self> {
+		Ok(match c {
+			'|' => Directions { up: 1, down: 1, left: 0, right: 0 },
+			'-' => Directions { up: 0, down: 0, left: 1, right: 1 },
+			'L' => Directions { up: 1, down: 0, left: 0, right: 1 },
+			'J' => Directions { up: 1, down: 0, left: 1, right: 0 },
+			'7' => Directions { up: 0, down: 1, left: 1, right: 0 },
+			'F' => Directions { up: 0, down: 1, left: 0, right: 1 },
+			'S' => Directions { up: 1, down: 1, left: 1, right: 1 }, //TODO
+			_ => bail!("`{c}` is not a pipe"),
+		})
+	}
+}
+
+impl std::ops::AddAssign for Directions {
+	fn add_assign(&mut self, rhs: Self) {
+		self.up += rhs.up;
+		self.down += rhs.down;
+		self.left += rhs.left;
+		self.right += rhs.right;
+	}
+}
+
+#[derive(Debug)]
+pub struct Sketch {
+	grid: Grid<char>,
+	/// Positions of the loop through the start, ending with the start.
+	pipe_loop: Vec<Position>,
+}
+
+impl Sketch {
+	fn get(&self, x: usize, y: usize) -> Option<char> {
+		self.grid.get(Position { x, y }).copied()
+	}
+
+	pub fn is_enclosed(&self, position: Position, l: &AHashSet<Position>) -> Result<bool> {
+		if l.contains(&position) {
+			return Ok(false);
+		}
+
+		let mut directions_x = Directions::default();
+		for ex in 0..position.x {
+			if l.contains(&Position { x: ex, y: position.y }) {
+				let pipe = self.get(ex, position.y).context("Loop position is not on the grid")?;
+				directions_x += Directions::try_from(pipe)?;
+			}
+		}
+		let mut directions_y = Directions::default();
+		for ey in 0..position.y {
+			if l.contains(&Position { x: position.x, y: ey }) {
+				let pipe = self.get(position.x, ey).context("Loop position is not on the grid")?;
+				directions_y += Directions::try_from(pipe)?;
+			}
+		}
+
+		Ok(directions_x.is_vertical() && directions_y.is_horizontal())
+	}
+}
+
+/// Follow the pipes from the start until they lead back to it. Errors point
+/// at the pipe in the input where the loop breaks.
+fn find_loop(grid: &Grid<char>, start: Position, input: &str) -> Result<Vec<Position>> {
+	// The grid only holds ASCII, so columns are byte offsets.
+	let pipe_text = |position: Position| {
+		let line = input.lines().nth(position.y).unwrap_or(input);
+		line.get(position.x..=position.x).unwrap_or(line)
+	};
+
+	// Follow the first neighbouring pipe that connects back to the start.
+	let mut current = [Direction::Right, Direction::Down, Direction::Left, Direction::Up]
+		.into_iter()
+		.find_map(|direction| {
+			let next = grid.step(start, direction)?;
+			connections(grid[next])?.contains(&direction.opposite()).then_some(next)
+		})
+		.or_parse_error(pipe_text(start), "No pipe connects to the start")?;
+	let mut previous = start;
+	let mut positions = vec![current];
+	while current != start {
+		let pipe = grid[current];
+		let [p1, p2] = connections(pipe)
+			.or_parse_error(pipe_text(current), "Expected a pipe")?
+			.map(|direction| grid.step(current, direction));
+		let next = match (p1 == Some(previous), p2 == Some(previous)) {
+			(true, _) => p2,
+			(_, true) => p1,
+			_ => bail!(ParseError::new(
+				pipe_text(current),
+				"Pipe does not connect to the one before"
+			)),
+		};
+		previous = current;
+		current = next.or_parse_error(pipe_text(previous), "Pipe leads off the grid")?;
+		positions.push(current);
+	}
+	Ok(positions)
+}
+
+/// Directions a pipe connects, `None` for ground and the start.
+fn connections(pipe: char) -> Option<[Direction; 2]> {
+	match pipe {
+		'|' => Some([Direction::Up, Direction::Down]),
+		'-' => Some([Direction::Left, Direction::Right]),
+		'L' => Some([Direction::Up, Direction::Right]),
+		'J' => Some([Direction::Up, Direction::Left]),
+		'7' => Some([Direction::Left, Direction::Down]),
+		'F' => Some([Direction::Right, Direction::Down]),
+		_ => None,
+	}
+}
+
+impl FromStr for Sketch {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let grid = Grid::parse_with(input, |c| match c {
+			'|' | '-' | 'L' | 'J' | '7' | 'F' | '.' | 'S' => Ok(c),
+			_ => Err("Expected a pipe, `.` or `S`"),
+		})?;
+		let start = grid.position(|&c| c == 'S').context("Could not find start 'S'")?;
+		let pipe_loop = find_loop(&grid, start, input)?;
+		Ok(Self { grid, pipe_loop })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	#[test]
+	fn part1() -> Result<()> {
+		let input = r#"
+		-L|F7
+		7S-7|
+		L|7||
+		-L-J|
+		L|-JF
+		"#;
+		let result = solve(&Day, Part::One, input)?;
+		assert_eq!(result, Answer::Int(4));
+
+		let input = r#"
+		7-F7-
+		.FJ|7
+		SJLL7
+		|F--J
+		LJ.LJ
+		"#;
+		let result = solve(&Day, Part::One, input)?;
+		assert_eq!(result, Answer::Int(8));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let input = r#"
+		...........
+		.S-------7.
+		.|F-----7|.
+		.||.....||.
+		.||.....||.
+		.|L-7.F-J|.
+		.|..|.|..|.
+		.L--J.L--J.
+		...........
+		"#;
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(4));
+
+		let input = r#"
+		.F----7F7F7F7F-7....
+		.|F--7||||||||FJ....
+		.||.FJ||||||||L7....
+		FJL7L7LJLJ||LJ.L-7..
+		L--J.L7...LJS7F-7L7.
+		....F-J..F7FJ|L7L7L7
+		....L7.F7||L7|.L7L7|
+		.....|FJLJ|FJ|F7|.LJ
+		....FJL-7.||.||||...
+		....L---J.LJ.LJLJ...
+		"#;
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(8));
+
+		let input = r#"
+		FF7FSF7F7F7F7F7F---7
+		L|LJ||||||||||||F--J
+		FL-7LJLJ||||||LJL-77
+		F--JF--7||LJLJ7F7FJ-
+		L---JF-JLJ.||-FJLJJ7
+		|F|F-JF---7F7-L7L|7|
+		|FFJF7L7F-JF7|JL---7
+		7-L-JL7||F7|L7F-7F7|
+		L.L7LFJ|||||FJL7||LJ
+		L7JLJL-JLJLJL--JLJ.L
+		"#;
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(10));
+
+		Ok(())
+	}
+
+	#[test]
+	fn malformed() {
+		let error = solve(&Day, Part::Two, "S7.\nLJ.\n..x").expect_err("invalid pipe");
+		assert_eq!(error.to_string(), "Expected a pipe, `.` or `S` at line 3, column 3");
+
+		// The pipes after the start run in a circle without it.
+		let error = solve(&Day, Part::One, "S-F7\n..LJ").expect_err("broken loop");
+		assert_eq!(error.to_string(), "Pipe does not connect to the one before at line 1, column 3");
+	}
+}
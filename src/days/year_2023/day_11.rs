@@ -0,0 +1,151 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+
+use super::{Answer, RunContext, Solution};
+use crate::grid::Grid;
+
+pub struct Day;
+
+impl Solution for Day {
+	type Parsed = Image;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Image::from_str(input)
+	}
+
+	fn part1(&self, image: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let mut image = image.clone();
+		image.expand(2);
+		let distances = image.sum_of_distances();
+		Ok(distances.into())
+	}
+
+	fn part2(&self, image: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let mut image = image.clone();
+		image.expand(1_000_000);
+		let distances = image.sum_of_distances();
+		Ok(distances.into())
+	}
+}
+
+impl Image {
+	pub fn expand(&mut self, factor: usize) {
+		for (x, mut column) in self.grid.columns().enumerate() {
+			if column.all(|field| *field == Field::Empty) {
+				self.cost_x[x] = factor;
+			}
+		}
+
+		for (y, row) in self.grid.rows().enumerate() {
+			if row.iter().all(|field| *field == Field::Empty) {
+				self.cost_y[y] = factor;
+			}
+		}
+	}
+
+	pub fn sum_of_distances(&self) -> usize {
+		let galaxies = self
+			.grid
+			.iter()
+			.filter(|(_, field)| **field == Field::Galaxy)
+			.map(|(position, _)| (position.x, position.y))
+			.collect::<Vec<_>>();
+
+		let mut distances = 0;
+		for i in 0..galaxies.len() {
+			for j in i + 1..galaxies.len() {
+				let mut distance = 0;
+
+				let small = galaxies[i].0.min(galaxies[j].0);
+				let big = galaxies[i].0.max(galaxies[j].0);
+				for x in small..big {
+					distance += self.cost_x[x];
+				}
+				let small = galaxies[i].1.min(galaxies[j].1);
+				let big = galaxies[i].1.max(galaxies[j].1);
+				for y in small..big {
+					distance += self.cost_y[y];
+				}
+
+				distances += distance;
+			}
+		}
+
+		distances
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum Field {
+	Empty,
+	Galaxy,
+}
+
+#[derive(Debug, Clone)]
+pub struct Image {
+	grid: Grid<Field>,
+	cost_x: Vec<usize>,
+	cost_y: Vec<usize>,
+}
+
+impl TryFrom<char> for Field {
+	type Error = anyhow::Error;
+
+	fn try_from(c: char) -> Result<Self> {
+		match c {
+			'.' => Ok(Self::Empty),
+			'#' => Ok(Self::Galaxy),
+			_ => bail!("Expected `.` or `#`"),
+		}
+	}
+}
+
+impl FromStr for Image {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let grid = Grid::<Field>::parse(input)?;
+		Ok(Self { cost_x: vec![1; grid.width()], cost_y: vec![1; grid.height()], grid })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{normalize, solve, Part};
+
+	const INPUT: &str = r#"
+		...#......
+		.......#..
+		#.........
+		..........
+		......#...
+		.#........
+		.........#
+		..........
+		.......#..
+		#...#.....
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(374));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let mut image = Image::from_str(&normalize(INPUT))?;
+		image.expand(100);
+		let distances = image.sum_of_distances();
+		assert_eq!(distances, 8410);
+
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(82000210));
+
+		Ok(())
+	}
+}
@@ -1,25 +1,26 @@
 use std::str::FromStr;
 
 use ahash::AHashMap;
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, Result};
 use rayon::prelude::*;
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::{self, OrParseError, ParseError};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let rows = Rows::from_str(input)?;
 		let sum_of_possible_arrangements = rows.sum_possible_arrangements();
-		Ok(sum_of_possible_arrangements.to_string())
+		Ok(sum_of_possible_arrangements.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let mut rows = Rows::from_str(input)?;
 		rows.expand();
 		let sum_of_possible_arrangements = rows.sum_possible_arrangements();
-		Ok(sum_of_possible_arrangements.to_string())
+		Ok(sum_of_possible_arrangements.into())
 	}
 }
 
@@ -137,18 +138,17 @@ impl FromStr for Row {
 
 	fn from_str(line: &str) -> Result<Self> {
 		let (records, continuous) =
-			line.trim().split_once(' ').context("line has no separating space")?;
+			line.split_once(' ').or_parse_error(line, "Expected records and group sizes")?;
 
-		let records = records
-			.chars()
-			.map(|c| match c {
+		let records = parse::chars(records)
+			.map(|(c, text)| match c {
 				'.' => Ok(Some(Status::Operational)),
 				'#' => Ok(Some(Status::Damaged)),
 				'?' => Ok(None),
-				_ => bail!("Invalid char '{c}'"),
+				_ => bail!(ParseError::new(text, "Expected `.`, `#` or `?`")),
 			})
 			.collect::<Result<_, _>>()?;
-		let continuous = continuous.split(',').map(usize::from_str).collect::<Result<_, _>>()?;
+		let continuous = continuous.split(',').map(parse::value).collect::<Result<_, _>>()?;
 
 		Ok(Self { records, continuous })
 	}
@@ -158,7 +158,7 @@ impl FromStr for Rows {
 	type Err = anyhow::Error;
 
 	fn from_str(input: &str) -> Result<Self> {
-		let rows = input.trim().lines().map(Row::from_str).collect::<Result<_, _>>()?;
+		let rows = input.lines().map(Row::from_str).collect::<Result<_, _>>()?;
 		Ok(Self(rows))
 	}
 }
@@ -166,6 +166,7 @@ impl FromStr for Rows {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		???.### 1,1,3
@@ -178,16 +179,16 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "21");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(21));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "525152");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(525152));
 
 		Ok(())
 	}
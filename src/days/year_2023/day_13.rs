@@ -0,0 +1,128 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+
+use super::{Answer, RunContext, Solution};
+use crate::grid::Grid;
+
+pub struct Day;
+
+impl Solution for Day {
+	type Parsed = Grids;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Grids::from_str(input)
+	}
+
+	fn part1(&self, grids: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let reflection_points = grids.reflection_points(0)?;
+		Ok(reflection_points.into())
+	}
+
+	fn part2(&self, grids: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let reflection_points = grids.reflection_points(1)?;
+		Ok(reflection_points.into())
+	}
+}
+
+impl Grids {
+	pub fn reflection_points(&self, defects: usize) -> Result<usize> {
+		self.0
+			.iter()
+			.enumerate()
+			.map(|(i, grid)| {
+				if let Some(horizonal) = horizontal_reflection(grid, defects) {
+					Ok(horizonal * 100)
+				} else if let Some(vertical) = horizontal_reflection(&grid.transpose(), defects) {
+					Ok(vertical)
+				} else {
+					bail!("Pattern {} has no reflection", i + 1);
+				}
+			})
+			.sum()
+	}
+}
+
+/// Number of rows above the horizontal line the grid reflects along with the
+/// expected number of differing tiles.
+fn horizontal_reflection(grid: &Grid<Item>, expected_defects: usize) -> Option<usize> {
+	let rows = grid.rows().collect::<Vec<_>>();
+	(1..rows.len()).find(|&i| {
+		let defects = (0..i)
+			.rev()
+			.zip(i..rows.len())
+			.map(|(a, b)| rows[a].iter().zip(rows[b]).filter(|(a, b)| a != b).count())
+			.sum::<usize>();
+		defects == expected_defects
+	})
+}
+
+#[derive(Debug)]
+pub struct Grids(Vec<Grid<Item>>);
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+enum Item {
+	Ash,  // .
+	Rock, // #
+}
+
+impl TryFrom<char> for Item {
+	type Error = &'static str;
+
+	fn try_from(c: char) -> Result<Self, Self::Error> {
+		match c {
+			'.' => Ok(Self::Ash),
+			'#' => Ok(Self::Rock),
+			_ => Err("Expected ash `.` or rock `#`"),
+		}
+	}
+}
+
+impl FromStr for Grids {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let grids = input.split("\n\n").map(Grid::parse).collect::<Result<_, _>>()?;
+		Ok(Self(grids))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	const INPUT: &str = r#"
+		#.##..##.
+		..#.##.#.
+		##......#
+		##......#
+		..#.##.#.
+		..##..##.
+		#.#.##.#.
+
+		#...##..#
+		#....#..#
+		..##..###
+		#####.##.
+		#####.##.
+		..##..###
+		#....#..#
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(405));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(400));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use anyhow::Result;
+
+use super::{Answer, RunContext, Solution};
+use crate::{cycle, geometry::Position, grid::Grid};
+
+pub struct Day;
+
+impl Solution for Day {
+	type Parsed = Platform;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Platform::from_str(input)
+	}
+
+	fn part1(&self, platform: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let mut platform = platform.clone();
+		platform.tilt_north();
+		let total_load = platform.total_load();
+		Ok(total_load.into())
+	}
+
+	fn part2(&self, platform: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let total_load = platform.load_after_spin_cycles(1_000_000_000);
+		Ok(total_load.into())
+	}
+}
+
+impl Platform {
+	pub fn total_load(&self) -> usize {
+		self.grid
+			.iter()
+			.filter(|(_, item)| **item == 'O')
+			.map(|(position, _)| self.grid.height() - position.y)
+			.sum()
+	}
+
+	/// Roll all round rocks north as far as they go.
+	pub fn tilt_north(&mut self) {
+		for x in 0..self.grid.width() {
+			let mut free = 0;
+			for y in 0..self.grid.height() {
+				match self.grid[Position { x, y }] {
+					'#' => free = y + 1,
+					'O' => {
+						self.grid[Position { x, y }] = '.';
+						self.grid[Position { x, y: free }] = 'O';
+						free += 1;
+					}
+					_ => {}
+				}
+			}
+		}
+	}
+
+	/// Tilt north, west, south and east. Turning the platform clockwise after
+	/// tilting north brings the next direction to the north.
+	pub fn spin_cycle(&mut self) {
+		for _ in 0..4 {
+			self.tilt_north();
+			self.grid = self.grid.rotate_clockwise();
+		}
+	}
+
+	/// Total load after the given number of spin cycles, which repeat after a
+	/// while.
+	pub fn load_after_spin_cycles(&self, cycles: usize) -> usize {
+		let spun = |platform: &Self| {
+			let mut platform = platform.clone();
+			platform.spin_cycle();
+			platform
+		};
+		let cycle = cycle::find_cycle(self.clone(), spun);
+		cycle.state_at(cycles, spun).total_load()
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone)]
+pub struct Platform {
+	grid: Grid<char>,
+}
+
+impl FromStr for Platform {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let grid = Grid::parse_with(input, |c| match c {
+			'O' | '#' | '.' => Ok(c),
+			_ => Err("Expected `O`, `#` or `.`"),
+		})?;
+		Ok(Self { grid })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{normalize, solve, Part};
+
+	const INPUT: &str = r#"
+		O....#....
+		O.OO#....#
+		.....##...
+		OO.#O....O
+		.O.....O#.
+		O.#..O.#.#
+		..O..#O..O
+		.......O..
+		#....###..
+		#OO..#....
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(136));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(64));
+
+		let platform = Platform::from_str(&normalize(INPUT))?;
+		let mut spun = platform.clone();
+		for cycles in 0..20 {
+			assert_eq!(platform.load_after_spin_cycles(cycles), spun.total_load(), "{cycles}");
+			spun.spin_cycle();
+		}
+
+		Ok(())
+	}
+}
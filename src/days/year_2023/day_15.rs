@@ -2,17 +2,20 @@ use std::str::FromStr;
 
 use anyhow::{bail, Result};
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::{self, ParseError};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	const ASCII_ONLY: bool = true;
+
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let sum_of_hashes = input.trim().split(',').map(hash).map(|hash| hash as u64).sum::<u64>();
-		Ok(sum_of_hashes.to_string())
+		Ok(sum_of_hashes.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let operations =
 			input.trim().split(',').map(Operation::from_str).collect::<Result<Vec<_>, _>>()?;
 
@@ -28,12 +31,12 @@ impl AocDay for Day {
 			}
 		}
 
-		Ok(focusing_power.to_string())
+		Ok(focusing_power.into())
 	}
 }
 
 fn hash(s: &str) -> u8 {
-	s.chars().map(|c| c as u8).fold(0, |hash, c| hash.wrapping_add(c).wrapping_mul(17))
+	s.bytes().fold(0, |hash, c| hash.wrapping_add(c).wrapping_mul(17))
 }
 
 impl Operation {
@@ -74,11 +77,11 @@ impl FromStr for Operation {
 
 	fn from_str(seq: &str) -> Result<Self> {
 		if let Some((label, lense)) = seq.split_once('=') {
-			Ok(Self::Add { label: label.to_owned(), lense: lense.parse()? })
+			Ok(Self::Add { label: label.to_owned(), lense: parse::value(lense)? })
 		} else if let Some((label, _)) = seq.split_once('-') {
 			Ok(Self::Remove { label: label.to_owned() })
 		} else {
-			bail!("Neither operation found");
+			bail!(ParseError::new(seq, "Expected `=` or `-`"));
 		}
 	}
 }
@@ -86,6 +89,7 @@ impl FromStr for Operation {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	#[test]
 	fn test_hash() {
@@ -99,16 +103,16 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "1320");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(1320));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "145");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(145));
 
 		Ok(())
 	}
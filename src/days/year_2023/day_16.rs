@@ -0,0 +1,146 @@
+use std::{collections::VecDeque, str::FromStr};
+
+use ahash::AHashSet;
+use anyhow::{ensure, Result};
+use rayon::prelude::*;
+
+use super::{Answer, RunContext, Solution};
+use crate::{
+	geometry::{Direction, Position},
+	grid::Grid,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+	type Parsed = Contraption;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Contraption::from_str(input)
+	}
+
+	fn part1(&self, contraption: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let energized = contraption.energized(Position { x: 0, y: 0 }, Direction::Right);
+		Ok(energized.into())
+	}
+
+	fn part2(&self, contraption: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let energized = contraption.max_energized()?;
+		Ok(energized.into())
+	}
+}
+
+impl Contraption {
+	pub fn max_energized(&self) -> Result<usize> {
+		let (width, height) = (self.grid.width(), self.grid.height());
+		ensure!(width > 0 && height > 0, "The contraption is empty");
+		let mut starts = Vec::new();
+		for x in 0..width {
+			starts.push((Position { x, y: 0 }, Direction::Down));
+			starts.push((Position { x, y: height - 1 }, Direction::Up));
+		}
+		for y in 0..height {
+			starts.push((Position { x: 0, y }, Direction::Right));
+			starts.push((Position { x: width - 1, y }, Direction::Left));
+		}
+
+		let energized = starts.into_par_iter().map(|(pos, dir)| self.energized(pos, dir)).max();
+		Ok(energized.expect("starts on every edge"))
+	}
+
+	pub fn energized(&self, start_pos: Position, start_direction: Direction) -> usize {
+		let mut seen = AHashSet::new();
+		let mut energized = AHashSet::new();
+		let mut rays = VecDeque::new();
+
+		energized.insert(start_pos);
+		let (start_direction, second_direction) = deflect(start_direction, self.grid[start_pos]);
+		rays.push_back((start_pos, start_direction));
+		seen.insert((start_pos, start_direction));
+		if let Some(start_direction) = second_direction {
+			rays.push_back((start_pos, start_direction));
+			seen.insert((start_pos, start_direction));
+		}
+
+		while let Some((pos, direction)) = rays.pop_front() {
+			if let Some(new_pos) = self.grid.step(pos, direction) {
+				energized.insert(new_pos);
+
+				let (new_direction, second_direction) = deflect(direction, self.grid[new_pos]);
+				if seen.insert((new_pos, new_direction)) {
+					rays.push_back((new_pos, new_direction));
+				}
+				if let Some(new_direction) = second_direction {
+					if seen.insert((new_pos, new_direction)) {
+						rays.push_back((new_pos, new_direction));
+					}
+				}
+			}
+		}
+
+		energized.len()
+	}
+}
+
+/// Directions a beam continues in after hitting the tile: mirrors turn it and
+/// splitters hit on their flat side split it.
+fn deflect(direction: Direction, tile: char) -> (Direction, Option<Direction>) {
+	match (tile, direction.is_vertical()) {
+		('-', true) | ('|', false) => (direction.turn_left(), Some(direction.turn_right())),
+		('/', true) | ('\\', false) => (direction.turn_right(), None),
+		('/', false) | ('\\', true) => (direction.turn_left(), None),
+		_ => (direction, None),
+	}
+}
+
+#[derive(Debug, Clone)]
+pub struct Contraption {
+	grid: Grid<char>,
+}
+
+impl FromStr for Contraption {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let grid = Grid::parse_with(input, |c| match c {
+			'.' | '/' | '\\' | '|' | '-' => Ok(c),
+			_ => Err("Expected `.`, a mirror or a splitter"),
+		})?;
+		Ok(Self { grid })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	const INPUT: &str = r#"
+		.|...\....
+		|.-.\.....
+		.....|-...
+		........|.
+		..........
+		.........\
+		..../.\\..
+		.-.-/..|..
+		.|....-|.\
+		..//.|....
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(46));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(51));
+
+		Ok(())
+	}
+}
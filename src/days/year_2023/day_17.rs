@@ -0,0 +1,136 @@
+use std::str::FromStr;
+
+use anyhow::{Context, Result};
+
+use super::{Answer, RunContext, Solution};
+use crate::{
+	geometry::{Direction, Position},
+	grid::Grid,
+	search,
+};
+
+pub struct Day;
+
+impl Solution for Day {
+	type Parsed = City;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		City::from_str(input)
+	}
+
+	fn part1(&self, city: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let least_heat_loss = city.least_heat_loss(0, 3).context("no path found")?;
+		Ok(least_heat_loss.into())
+	}
+
+	fn part2(&self, city: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let least_heat_loss = city.least_heat_loss(4, 10).context("no path found")?;
+		Ok(least_heat_loss.into())
+	}
+}
+
+impl City {
+	pub fn least_heat_loss(&self, min_forward: usize, max_forward: usize) -> Option<u32> {
+		let target = Position { x: self.grid.width() - 1, y: self.grid.height() - 1 };
+		let starts = [Direction::Right, Direction::Down]
+			.map(|direction| Crucible { position: Position::default(), direction, num_steps: 0 });
+		let path = search::dijkstra(
+			starts,
+			|crucible| self.moves(*crucible, min_forward, max_forward),
+			|crucible| crucible.position == target && crucible.num_steps >= min_forward,
+		)?;
+		Some(path.cost)
+	}
+
+	/// Moves of the crucible with their heat loss.
+	fn moves(
+		&self,
+		crucible: Crucible,
+		min_forward: usize,
+		max_forward: usize,
+	) -> impl Iterator<Item = (Crucible, u32)> + '_ {
+		Direction::ALL.into_iter().filter_map(move |direction| {
+			let forward = direction == crucible.direction;
+			if direction == crucible.direction.opposite()
+				|| (forward && crucible.num_steps >= max_forward)
+				|| (!forward && crucible.num_steps < min_forward)
+			{
+				return None;
+			}
+			let position = self.grid.step(crucible.position, direction)?;
+			let num_steps = if forward { crucible.num_steps + 1 } else { 1 };
+			Some((Crucible { position, direction, num_steps }, self.grid[position] as u32))
+		})
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+struct Crucible {
+	position: Position,
+	direction: Direction,
+	num_steps: usize,
+}
+
+#[derive(Debug)]
+pub struct City {
+	grid: Grid<u8>,
+}
+
+impl FromStr for City {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let grid = Grid::parse_with(input, |c| {
+			c.to_digit(10).map(|heat_loss| heat_loss as u8).ok_or("Expected a digit")
+		})?;
+		Ok(Self { grid })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	const INPUT: &str = r#"
+		2413432311323
+		3215453535623
+		3255245654254
+		3446585845452
+		4546657867536
+		1438598798454
+		4457876987766
+		3637877979653
+		4654967986887
+		4564679986453
+		1224686865563
+		2546548887735
+		4322674655533
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(102));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(94));
+
+		let input = r#"
+		111111111111
+		999999999991
+		999999999991
+		999999999991
+		999999999991
+		"#;
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(71));
+
+		Ok(())
+	}
+}
@@ -1,41 +1,43 @@
-use std::str::FromStr;
-
-use anyhow::{bail, Context, Result};
+use anyhow::Result;
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::{
+	geometry::{Direction, Point},
+	parse::{self, OrParseError, ParseError},
+};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let instructions = parse_instructions_1(input)?;
 		let mut world = World::default();
 		world.set_corner_points(instructions);
 
 		let total_space = world.total_space_filled();
-		Ok(total_space.to_string())
+		Ok(total_space.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let instructions = parse_instructions_2(input)?;
 		let mut world = World::default();
 		world.set_corner_points(instructions);
 
 		let total_space = world.total_space_filled();
-		Ok(total_space.to_string())
+		Ok(total_space.into())
 	}
 }
 
 impl World {
 	pub fn set_corner_points(&mut self, instructions: Vec<Instruction>) {
-		let mut pos = Position { x: 0, y: 0 };
+		let mut pos = Point::new(0, 0);
 		self.corners.push(pos);
 		let mut total_length = 0;
 		for instruction in instructions {
 			total_length += instruction.steps;
-			pos = pos.go(instruction.direction, instruction.steps);
+			pos = pos.go(instruction.direction, instruction.steps as isize);
 			self.corners.push(pos);
 		}
 		self.perimeter_length = total_length;
@@ -52,67 +54,40 @@ impl World {
 	}
 }
 
-impl Position {
-	pub fn go(self, direction: Direction, steps: usize) -> Self {
-		let steps = steps as isize;
-		match direction {
-			Direction::Up => Self { x: self.x, y: self.y - steps },
-			Direction::Right => Self { x: self.x + steps, y: self.y },
-			Direction::Down => Self { x: self.x, y: self.y + steps },
-			Direction::Left => Self { x: self.x - steps, y: self.y },
-		}
-	}
-}
-
-#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
-struct Position {
-	x: isize,
-	y: isize,
-}
-
 #[derive(Debug, Default)]
 struct World {
-	corners: Vec<Position>,
+	corners: Vec<Point>,
 	perimeter_length: usize,
 }
 
-#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
-enum Direction {
-	Up,
-	Right,
-	Down,
-	Left,
-}
-
 struct Instruction {
 	direction: Direction,
 	steps: usize,
 }
 
-impl FromStr for Direction {
-	type Err = anyhow::Error;
-
-	fn from_str(s: &str) -> Result<Self> {
-		match s {
-			"U" | "3" => Ok(Self::Up),
-			"R" | "0" => Ok(Self::Right),
-			"D" | "1" => Ok(Self::Down),
-			"L" | "2" => Ok(Self::Left),
-			_ => bail!("Invalid direction `{s}`"),
-		}
+/// Direction from its letter or, in the color, its digit.
+fn direction(text: &str) -> Result<Direction, ParseError> {
+	match text {
+		"U" | "3" => Ok(Direction::Up),
+		"R" | "0" => Ok(Direction::Right),
+		"D" | "1" => Ok(Direction::Down),
+		"L" | "2" => Ok(Direction::Left),
+		_ => Err(ParseError::new(text, "Expected a direction")),
 	}
 }
 
+const LINE_FORMAT: &str = "Expected `<direction> <steps> (#<color>)`";
+
 static LINE_REGEX: Lazy<Regex> = Lazy::new(|| {
 	Regex::new(r"(U|R|D|L) (\d+) \(#([[:xdigit:]]{5})(\d)\)").expect("creating regex")
 });
 
 fn parse_instructions_1(input: &str) -> Result<Vec<Instruction>> {
 	let mut instructions = Vec::new();
-	for line in input.trim().lines() {
-		let captures = LINE_REGEX.captures(line).context("apply line regex")?;
+	for line in input.lines() {
+		let captures = LINE_REGEX.captures(line).or_parse_error(line, LINE_FORMAT)?;
 		let instruction =
-			Instruction { direction: captures[1].parse()?, steps: captures[2].parse()? };
+			Instruction { direction: direction(&captures[1])?, steps: parse::value(&captures[2])? };
 		instructions.push(instruction);
 	}
 	Ok(instructions)
@@ -120,11 +95,12 @@ fn parse_instructions_1(input: &str) -> Result<Vec<Instruction>> {
 
 fn parse_instructions_2(input: &str) -> Result<Vec<Instruction>> {
 	let mut instructions = Vec::new();
-	for line in input.trim().lines() {
-		let captures = LINE_REGEX.captures(line).context("apply line regex")?;
+	for line in input.lines() {
+		let captures = LINE_REGEX.captures(line).or_parse_error(line, LINE_FORMAT)?;
+		let steps = &captures[3];
 		let instruction = Instruction {
-			direction: captures[4].parse()?,
-			steps: usize::from_str_radix(&captures[3], 16)?,
+			direction: direction(&captures[4])?,
+			steps: usize::from_str_radix(steps, 16).map_err(|err| ParseError::new(steps, err))?,
 		};
 		instructions.push(instruction);
 	}
@@ -134,6 +110,7 @@ fn parse_instructions_2(input: &str) -> Result<Vec<Instruction>> {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		R 6 (#70c710)
@@ -154,16 +131,16 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "62");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(62));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "952408144115");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(952408144115));
 
 		Ok(())
 	}
@@ -3,13 +3,19 @@ use std::{collections::VecDeque, ops::RangeInclusive, str::FromStr};
 use ahash::AHashMap;
 use anyhow::{bail, Context, Result};
 
-use super::AocDay;
+use super::{Answer, RunContext, Solution};
+use crate::parse::{self, OrParseError, ParseError};
 
 pub struct Day;
 
-impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
-		let workflows = Workflows::from_str(input)?;
+impl Solution for Day {
+	type Parsed = Workflows;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Workflows::from_str(input)
+	}
+
+	fn part1(&self, workflows: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
 		let mut score = 0;
 		for item in workflows.items() {
 			if workflows.run_workflow(item)? {
@@ -17,13 +23,12 @@ impl AocDay for Day {
 				score += sum;
 			}
 		}
-		Ok(score.to_string())
+		Ok(score.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
-		let workflows = Workflows::from_str(input)?;
+	fn part2(&self, workflows: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
 		let num_accepted_items = workflows.num_any_accepted()?;
-		Ok(num_accepted_items.to_string())
+		Ok(num_accepted_items.into())
 	}
 }
 
@@ -51,12 +56,12 @@ impl Workflows {
 					}
 					Rule::LessThan { var, number, target } => {
 						branch_ranges[*var] = (*branch_ranges[*var].start())
-							..=(*branch_ranges[*var].end().min(number) - 1);
+							..=(branch_ranges[*var].end().min(number).saturating_sub(1));
 						ranges[*var] = (*ranges[*var].start().max(number))..=(*ranges[*var].end());
 						queue.push_back((target, branch_ranges));
 					}
 					Rule::GreaterThan { var, number, target } => {
-						branch_ranges[*var] = (*branch_ranges[*var].start().max(number) + 1)
+						branch_ranges[*var] = (branch_ranges[*var].start().max(number).saturating_add(1))
 							..=(*branch_ranges[*var].end());
 						ranges[*var] = (*ranges[*var].start())..=(*ranges[*var].end().min(number));
 						queue.push_back((target, branch_ranges));
@@ -91,12 +96,12 @@ impl Workflow {
 			match rule {
 				Rule::Always { target } => return Ok(target),
 				Rule::LessThan { var, number, target } => {
-					if item[var] < *number {
+					if rating(item, *var)? < *number {
 						return Ok(target);
 					}
 				}
 				Rule::GreaterThan { var, number, target } => {
-					if item[var] > *number {
+					if rating(item, *var)? > *number {
 						return Ok(target);
 					}
 				}
@@ -106,6 +111,10 @@ impl Workflow {
 	}
 }
 
+fn rating(item: &AHashMap<char, u32>, var: char) -> Result<u32> {
+	item.get(&var).copied().with_context(|| format!("Item has no `{var}` rating"))
+}
+
 impl ItemRanges {
 	pub fn possibilities(self) -> usize {
 		let len_x = self.x.count();
@@ -167,7 +176,7 @@ struct Workflow {
 }
 
 #[derive(Debug)]
-struct Workflows {
+pub struct Workflows {
 	/// Map from workflow name to workflow info.
 	workflows: AHashMap<String, Workflow>,
 	/// Map from variable name to value.
@@ -182,15 +191,15 @@ impl FromStr for Workflow {
 		for rule in line.split(',') {
 			if let Some((condition, target)) = rule.split_once(':') {
 				if let Some((var, number)) = condition.split_once('<') {
-					let var = var.chars().nth(0).context("Empty var")?;
-					let number = number.parse()?;
+					let var = category(var)?;
+					let number = parse::value(number)?;
 					rules.push(Rule::LessThan { var, number, target: target.to_owned() });
 				} else if let Some((var, number)) = condition.split_once('>') {
-					let var = var.chars().nth(0).context("Empty var")?;
-					let number = number.parse()?;
+					let var = category(var)?;
+					let number = parse::value(number)?;
 					rules.push(Rule::GreaterThan { var, number, target: target.to_owned() });
 				} else {
-					bail!("Invalid condition `{condition}`");
+					bail!(ParseError::new(condition, "Expected `<` or `>`"));
 				}
 			} else {
 				rules.push(Rule::Always { target: rule.to_owned() });
@@ -200,17 +209,33 @@ impl FromStr for Workflow {
 	}
 }
 
+/// Category of a rating, one of `x`, `m`, `a` and `s`.
+fn category(text: &str) -> Result<char, ParseError> {
+	match text {
+		"x" => Ok('x'),
+		"m" => Ok('m'),
+		"a" => Ok('a'),
+		"s" => Ok('s'),
+		_ => Err(ParseError::new(text, "Expected a category: `x`, `m`, `a` or `s`")),
+	}
+}
+
 impl FromStr for Workflows {
 	type Err = anyhow::Error;
 
 	fn from_str(input: &str) -> Result<Self> {
-		let (workflows, items) =
-			input.trim().split_once("\n\n").context("Could not find double newline")?;
+		let (workflows, items) = input
+			.split_once("\n\n")
+			.or_parse_error(input, "Expected an empty line after the workflows")?;
 		let workflows = workflows
 			.lines()
 			.map(|line| {
-				let (name, workflow) = line.trim().split_once('{').context("Parsing workflow")?;
-				let workflow = workflow.trim_end_matches('}').parse::<Workflow>()?;
+				let line = line.trim_start();
+				let (name, workflow) = line
+					.strip_suffix('}')
+					.and_then(|line| line.split_once('{'))
+					.or_parse_error(line, "Expected `<name>{<rules>}`")?;
+				let workflow = workflow.parse::<Workflow>()?;
 				Ok::<_, anyhow::Error>((name.to_owned(), workflow))
 			})
 			.collect::<Result<_, _>>()?;
@@ -218,11 +243,15 @@ impl FromStr for Workflows {
 			.lines()
 			.map(|line| {
 				let mut item = AHashMap::new();
-				for var in line.trim().trim_start_matches('{').trim_end_matches('}').split(',') {
+				let ratings = line
+					.strip_prefix('{')
+					.and_then(|line| line.strip_suffix('}'))
+					.or_parse_error(line, "Expected `{<ratings>}`")?;
+				for rating in ratings.split(',') {
 					let (var, number) =
-						var.split_once('=').context("Invalid variable assignment")?;
-					let var = var.chars().nth(0).context("Empty variable name")?;
-					let number = number.parse()?;
+						rating.split_once('=').or_parse_error(rating, "Expected `<category>=<rating>`")?;
+					let var = category(var)?;
+					let number = parse::value(number)?;
 					item.insert(var, number);
 				}
 				Ok::<_, anyhow::Error>(item)
@@ -235,6 +264,7 @@ impl FromStr for Workflows {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		px{a<2006:qkq,m>2090:A,rfg}
@@ -258,8 +288,8 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "19114");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(19114));
 
 		Ok(())
 	}
@@ -273,8 +303,8 @@ mod tests {
 
 		{x=1,m=1,a=1,s=1}
 		"#;
-		let result = Day.part2(input)?;
-		assert_eq!(result.as_str(), "128000000000000");
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(128000000000000));
 
 		let input = r#"
 		in{s<1351:px,in2}
@@ -291,11 +321,11 @@ mod tests {
 
 		{x=1,m=1,a=1,s=1}
 		"#;
-		let result = Day.part2(input)?;
-		assert_eq!(result.as_str(), "167409079868000");
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(167409079868000));
 
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "167409079868000");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(167409079868000));
 
 		Ok(())
 	}
@@ -1,26 +1,27 @@
 use std::str::FromStr;
 
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, Result};
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::{self, OrParseError, ParseError};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let games = Games::from_str(input)?;
 
 		let sum_of_possible = games.sum_of_possible_ids(12, 13, 14);
 
-		Ok(sum_of_possible.to_string())
+		Ok(sum_of_possible.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let games = Games::from_str(input)?;
 
 		let sum_of_powers = games.sum_of_powers();
 
-		Ok(sum_of_powers.to_string())
+		Ok(sum_of_powers.into())
 	}
 }
 
@@ -39,7 +40,7 @@ impl FromStr for Color {
 			"red" => Ok(Self::Red),
 			"green" => Ok(Self::Green),
 			"blue" => Ok(Self::Blue),
-			_ => bail!("Invalid color `{}`", s),
+			_ => bail!(ParseError::new(s, "Invalid color")),
 		}
 	}
 }
@@ -53,8 +54,8 @@ impl FromStr for Shown {
 	type Err = anyhow::Error;
 
 	fn from_str(s: &str) -> Result<Self> {
-		let (num, color) = s.trim().split_once(' ').context("Invalid 'shown' string")?;
-		Ok(Self { color: color.parse()?, number: num.parse()? })
+		let (num, color) = s.split_once(' ').or_parse_error(s, "Expected a number and a color")?;
+		Ok(Self { color: color.parse()?, number: parse::value(num)? })
 	}
 }
 
@@ -67,15 +68,15 @@ impl FromStr for Game {
 	type Err = anyhow::Error;
 
 	fn from_str(s: &str) -> Result<Self> {
-		let (game, showns) = s.trim().split_once(": ").context("Invalid game string")?;
-		let id = game.split_once(' ').context("Invalig game ID")?.1;
+		let (game, showns) = s.split_once(": ").or_parse_error(s, "Expected `Game <id>: `")?;
+		let id = game.strip_prefix("Game ").or_parse_error(game, "Expected `Game <id>`")?;
 
 		let shown = showns
 			.split("; ")
 			.map(|shown| shown.split(", ").map(Shown::from_str).collect::<Result<_, _>>())
 			.collect::<Result<_, _>>()?;
 
-		Ok(Self { id: id.parse()?, shown })
+		Ok(Self { id: parse::value(id)?, shown })
 	}
 }
 
@@ -117,7 +118,7 @@ impl FromStr for Games {
 	type Err = anyhow::Error;
 
 	fn from_str(input: &str) -> Result<Self> {
-		input.trim().lines().map(Game::from_str).collect::<Result<_, _>>().map(Self)
+		input.lines().map(Game::from_str).collect::<Result<_, _>>().map(Self)
 	}
 }
 
@@ -138,6 +139,7 @@ impl Games {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		Game 1: 3 blue, 4 red; 1 red, 2 green, 6 blue; 2 green
@@ -149,16 +151,16 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "8");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(8));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "2286");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(2286));
 
 		Ok(())
 	}
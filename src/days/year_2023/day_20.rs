@@ -1,17 +1,27 @@
 use std::{collections::VecDeque, str::FromStr};
 
 use ahash::AHashMap;
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, Result};
 use once_cell::sync::Lazy;
 use regex::Regex;
 
-use super::AocDay;
+use super::{Answer, RunContext, Solution};
+use crate::parse::{OrParseError, ParseError};
 
 pub struct Day;
 
-impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
-		let mut network = Network::from_str(input)?;
+/// Number of button presses between progress reports in part 2.
+const PROGRESS_PRESSES: u64 = 10_000;
+
+impl Solution for Day {
+	type Parsed = Network;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Network::from_str(input)
+	}
+
+	fn part1(&self, network: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let mut network = network.clone();
 		let mut low_pulses = 0;
 		let mut high_pulses = 0;
 		for _ in 0..1000 {
@@ -20,16 +30,20 @@ impl AocDay for Day {
 			high_pulses += high;
 		}
 		let score = low_pulses * high_pulses;
-		Ok(score.to_string())
+		Ok(score.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
-		let mut network = Network::from_str(input)?;
+	fn part2(&self, network: &Self::Parsed, ctx: &RunContext) -> Result<Answer> {
+		let mut network = network.clone();
 		let mut times_pressed = 1;
 		while !network.cycle()?.2 {
 			times_pressed += 1;
+			if times_pressed % PROGRESS_PRESSES == 0 {
+				ctx.check_cancelled()?;
+				ctx.count(times_pressed);
+			}
 		}
-		Ok(times_pressed.to_string())
+		Ok(times_pressed.into())
 	}
 }
 
@@ -125,21 +139,21 @@ impl Module {
 	}
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 enum ModuleType {
 	Broadcast,
 	FlipFlop { on: bool },
 	Conjunction { last_pulse_high: AHashMap<String, bool> },
 }
 
-#[derive(Debug)]
+#[derive(Debug, Clone)]
 struct Module {
 	ty: ModuleType,
 	next: Vec<String>,
 }
 
-#[derive(Debug)]
-struct Network {
+#[derive(Debug, Clone)]
+pub struct Network {
 	modules: AHashMap<String, Module>,
 }
 
@@ -151,8 +165,10 @@ impl FromStr for Network {
 			Lazy::new(|| Regex::new(r"(%|&)?([a-z]+) -> ((?:[a-z]+,? ?)+)").expect("create regex"));
 
 		let mut modules = AHashMap::new();
-		for line in input.trim().lines() {
-			let captures = REGEX.captures(line).context("applying regex")?;
+		for line in input.lines() {
+			let captures = REGEX
+				.captures(line)
+				.or_parse_error(line, "Expected `<type><name> -> <destinations>`")?;
 			let mut next = Vec::new();
 			for n in captures[3].split(", ") {
 				next.push(n.to_owned());
@@ -179,7 +195,7 @@ impl FromStr for Network {
 						},
 					);
 				}
-				_ => bail!("Invalid module line: `{line}`"),
+				_ => bail!(ParseError::new(&captures[2], "Expected `%`, `&` or `broadcaster`")),
 			}
 		}
 
@@ -193,6 +209,7 @@ impl FromStr for Network {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT1: &str = r#"
 		broadcaster -> a, b, c
@@ -211,10 +228,10 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT1)?;
-		assert_eq!(result.as_str(), "32000000");
-		let result = Day.part1(INPUT2)?;
-		assert_eq!(result.as_str(), "11687500");
+		let result = solve(&Day, Part::One, INPUT1)?;
+		assert_eq!(result, Answer::Int(32000000));
+		let result = solve(&Day, Part::One, INPUT2)?;
+		assert_eq!(result, Answer::Int(11687500));
 		Ok(())
 	}
 }
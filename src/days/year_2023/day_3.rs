@@ -1,30 +1,34 @@
 use anyhow::Result;
 use regex::Regex;
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse;
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	// Positions are byte offsets of the lines.
+	const ASCII_ONLY: bool = true;
+
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let number_regex = Regex::new(r"[0-9]+")?;
-		let lines = input.trim().lines().map(|line| line.trim()).collect::<Vec<_>>();
+		let lines = parse::grid_lines(input)?;
 
 		let mut total: u64 = 0;
 		for i in 0..lines.len() {
 			for number_match in number_regex.find_iter(lines[i]) {
 				if is_number_adjacent_to_symbol(&lines, i, number_match.start(), number_match.end())
 				{
-					let number: u64 = number_match.as_str().parse()?;
+					let number: u64 = parse::value(number_match.as_str())?;
 					total += number;
 				}
 			}
 		}
-		Ok(total.to_string())
+		Ok(total.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
-		let lines = input.trim().lines().map(|line| line.trim()).collect::<Vec<_>>();
+	fn part2(&self, input: &str) -> Result<Answer> {
+		let lines = parse::grid_lines(input)?;
 
 		let mut total = 0;
 		for i in 0..lines.len() {
@@ -34,7 +38,7 @@ impl AocDay for Day {
 				}
 			}
 		}
-		Ok(total.to_string())
+		Ok(total.into())
 	}
 }
 
@@ -90,7 +94,7 @@ fn find_adjacent_numbers(
 		}
 	}
 
-	if lines[line_i].chars().nth(start).unwrap().is_numeric() {
+	if lines[line_i].chars().nth(start).unwrap_or('.').is_numeric() {
 		let second = find_number(lines[line_i], start)?;
 		if let Some(first) = first {
 			return Ok(Some((first, second)));
@@ -133,18 +137,19 @@ fn find_adjacent_numbers(
 fn find_number(line: &str, start: usize) -> Result<u64> {
 	let mut start = start;
 	let mut end = start + 1;
-	while start > 0 && line.chars().nth(start - 1).unwrap().is_numeric() {
+	while start > 0 && line.chars().nth(start - 1).unwrap_or('.').is_numeric() {
 		start -= 1;
 	}
 	while line.chars().nth(end).unwrap_or('.').is_numeric() {
 		end += 1;
 	}
-	Ok(line[start..end].parse()?)
+	Ok(parse::value(&line[start..end])?)
 }
 
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		467..114..
@@ -161,16 +166,16 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "4361");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(4361));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "467835");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(467835));
 
 		Ok(())
 	}
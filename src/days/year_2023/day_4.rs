@@ -3,21 +3,22 @@ use std::{
 	str::FromStr,
 };
 
-use anyhow::{Context, Result};
+use anyhow::Result;
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::{self, OrParseError};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let cards = Cards::from_str(input)?;
-		Ok(cards.worth_points().to_string())
+		Ok(cards.worth_points().into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let cards = Cards::from_str(input)?;
-		Ok(cards.total_scratch_cards().to_string())
+		Ok(cards.total_scratch_cards().into())
 	}
 }
 
@@ -33,15 +34,15 @@ impl FromStr for Card {
 	type Err = anyhow::Error;
 
 	fn from_str(line: &str) -> Result<Self> {
-		let (card, numbers) = line.trim().split_once(": ").context("line split ': '")?;
-		let (winning, you_have) = numbers.split_once(" | ").context("numbers split ' | '")?;
+		let (card, numbers) = line.split_once(": ").or_parse_error(line, "Expected `Card <id>: `")?;
+		let (winning, you_have) =
+			numbers.split_once(" | ").or_parse_error(numbers, "Expected numbers split by ` | `")?;
 
-		let id = card.rsplit_once(' ').context("card split ' '")?.1;
-		let winning = winning.split_whitespace().map(usize::from_str).collect::<Result<_, _>>()?;
-		let you_have =
-			you_have.split_whitespace().map(usize::from_str).collect::<Result<_, _>>()?;
+		let id = card.rsplit_once(' ').or_parse_error(card, "Expected `Card <id>`")?.1;
+		let winning = winning.split_whitespace().map(parse::value).collect::<Result<_, _>>()?;
+		let you_have = you_have.split_whitespace().map(parse::value).collect::<Result<_, _>>()?;
 
-		Ok(Self { id: id.parse()?, winning, you_have })
+		Ok(Self { id: parse::value(id)?, winning, you_have })
 	}
 }
 
@@ -57,7 +58,7 @@ impl FromStr for Cards {
 	type Err = anyhow::Error;
 
 	fn from_str(input: &str) -> Result<Self> {
-		input.trim().lines().map(Card::from_str).collect::<Result<_, _>>().map(Self)
+		input.lines().map(Card::from_str).collect::<Result<_, _>>().map(Self)
 	}
 }
 
@@ -86,6 +87,7 @@ impl Cards {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		Card 1: 41 48 83 86 17 | 83 86  6 31 17  9 48 53
@@ -98,16 +100,16 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "13");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(13));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "30");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(30));
 
 		Ok(())
 	}
@@ -0,0 +1,213 @@
+use std::{
+	ops::Range,
+	str::FromStr,
+	sync::atomic::{AtomicU64, Ordering},
+};
+
+use anyhow::{bail, Context, Result};
+use rayon::prelude::*;
+
+use super::{Answer, RunContext, Solution};
+use crate::parse::{self, OrParseError, ParseError};
+
+pub struct Day;
+
+/// Number of seeds mapped between progress reports in part 2.
+const CHUNK_SIZE: u64 = 1 << 16;
+
+impl Solution for Day {
+	type Parsed = Data;
+
+	fn parse(&self, input: &str) -> Result<Self::Parsed> {
+		Data::from_str(input)
+	}
+
+	fn part1(&self, data: &Self::Parsed, _ctx: &RunContext) -> Result<Answer> {
+		let min_mapped = data
+			.seeds
+			.iter()
+			.map(|seed| data.location(*seed))
+			.min()
+			.context("Finding minimum mapped location")?;
+
+		Ok(min_mapped.into())
+	}
+
+	fn part2(&self, data: &Self::Parsed, ctx: &RunContext) -> Result<Answer> {
+		let mut chunks = Vec::<Range<u64>>::new();
+		for chunk in data.seeds.chunks_exact(2) {
+			let &[start, length] = chunk else {
+				unreachable!("chunks are always exact 2");
+			};
+			let end = start + length;
+			chunks.extend(
+				(start..end)
+					.step_by(CHUNK_SIZE as usize)
+					.map(|chunk_start| chunk_start..end.min(chunk_start + CHUNK_SIZE)),
+			);
+		}
+
+		let total = chunks.iter().map(|chunk| chunk.end - chunk.start).sum();
+		let done = AtomicU64::new(0);
+		let min_mapped = chunks
+			.into_par_iter()
+			.map(|chunk| {
+				ctx.check_cancelled()?;
+				let length = chunk.end - chunk.start;
+				let min = chunk.map(|seed| data.location(seed)).min();
+				ctx.progress(done.fetch_add(length, Ordering::Relaxed) + length, total);
+				Ok(min)
+			})
+			.collect::<Result<Vec<_>>>()?
+			.into_iter()
+			.flatten()
+			.min()
+			.context("Finding minimum mapped location")?;
+
+		Ok(min_mapped.into())
+	}
+}
+
+pub struct Data {
+	seeds: Vec<u64>,
+	maps: Vec<Map>,
+}
+
+impl Data {
+	/// Map a seed through all maps to its location.
+	fn location(&self, seed: u64) -> u64 {
+		self.maps.iter().fold(seed, |source, map| map.map_min(source))
+	}
+}
+
+impl FromStr for Data {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let mut segments = input.split("\n\n");
+		let seeds = segments.next().or_parse_error(input, "Expected the seeds")?;
+		let seeds = seeds.strip_prefix("seeds:").or_parse_error(seeds, "Expected `seeds:`")?;
+		let seeds = seeds.split_whitespace().map(parse::value).collect::<Result<_, _>>()?;
+
+		let maps = segments.map(Map::from_str).collect::<Result<_, _>>()?;
+
+		Ok(Self { seeds, maps })
+	}
+}
+
+struct Map {
+	map: Vec<MapItem>,
+}
+
+impl Map {
+	fn map_min(&self, source: u64) -> u64 {
+		self.map.iter().filter_map(|map| map.map_min(source)).min().unwrap_or(source)
+	}
+}
+
+impl FromStr for Map {
+	type Err = anyhow::Error;
+
+	fn from_str(map_str: &str) -> Result<Self> {
+		let mappings =
+			map_str.lines().skip(1).map(MapItem::from_str).collect::<Result<_, _>>()?;
+		Ok(Self { map: mappings })
+	}
+}
+
+struct MapItem {
+	destination: u64,
+	source: u64,
+	range_len: u64,
+}
+
+impl MapItem {
+	fn map_min(&self, source: u64) -> Option<u64> {
+		// panics with subtraction wrapping otherwise.
+		#[allow(clippy::unnecessary_lazy_evaluations)]
+		(self.source..(self.source + self.range_len))
+			.contains(&source)
+			.then(|| self.destination + source - self.source)
+	}
+}
+
+impl FromStr for MapItem {
+	type Err = anyhow::Error;
+
+	fn from_str(line: &str) -> Result<Self> {
+		let numbers =
+			line.split_whitespace().map(parse::value).collect::<Result<Vec<u64>, _>>()?;
+
+		if numbers.len() != 3 {
+			bail!(ParseError::new(line, "Expected exactly three numbers"));
+		}
+
+		Ok(Self { destination: numbers[0], source: numbers[1], range_len: numbers[2] })
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	const INPUT: &str = r#"
+		seeds: 79 14 55 13
+
+		seed-to-soil map:
+		50 98 2
+		52 50 48
+
+		soil-to-fertilizer map:
+		0 15 37
+		37 52 2
+		39 0 15
+
+		fertilizer-to-water map:
+		49 53 8
+		0 11 42
+		42 0 7
+		57 7 4
+
+		water-to-light map:
+		88 18 7
+		18 25 70
+
+		light-to-temperature map:
+		45 77 23
+		81 45 19
+		68 64 13
+
+		temperature-to-humidity map:
+		0 69 1
+		1 0 69
+
+		humidity-to-location map:
+		60 56 37
+		56 93 4
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(35));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(46));
+
+		Ok(())
+	}
+
+	#[test]
+	fn crlf() -> Result<()> {
+		let result = solve(&Day, Part::One, &INPUT.replace('\n', "\r\n"))?;
+		assert_eq!(result, Answer::Int(35));
+
+		Ok(())
+	}
+}
@@ -0,0 +1,121 @@
+use std::str::FromStr;
+
+use anyhow::{bail, Result};
+
+use super::{Answer, AocDay};
+use crate::parse::{self, OrParseError, ParseError};
+
+pub struct Day;
+
+impl AocDay for Day {
+	fn part1(&self, input: &str) -> Result<Answer> {
+		let races = Races::from_str(input)?;
+
+		let mut factor = 1;
+		for race in races.races {
+			factor *= race.ways_to_beat_distance();
+		}
+
+		Ok(factor.into())
+	}
+
+	fn part2(&self, input: &str) -> Result<Answer> {
+		let race = Race::from_str(input)?;
+
+		let ways_to_beat = race.ways_to_beat_distance();
+
+		Ok(ways_to_beat.into())
+	}
+}
+
+struct Races {
+	races: Vec<Race>,
+}
+
+struct Race {
+	time: u64,
+	record: u64,
+}
+
+impl FromStr for Races {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let (times, distances) = lines(input)?;
+		let times = times.split_whitespace().map(parse::value).collect::<Result<Vec<_>, _>>()?;
+		let records =
+			distances.split_whitespace().map(parse::value).collect::<Result<Vec<_>, _>>()?;
+		if times.len() != records.len() {
+			bail!(ParseError::new(distances, "Expected as many distances as times"));
+		}
+
+		let races =
+			times.into_iter().zip(records).map(|(time, record)| Race { time, record }).collect();
+		Ok(Self { races })
+	}
+}
+
+impl FromStr for Race {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let (time, distance) = lines(input)?;
+		Ok(Self { time: kerned_number(time)?, record: kerned_number(distance)? })
+	}
+}
+
+/// Numbers of the time and the distance line, without their labels.
+fn lines(input: &str) -> Result<(&str, &str), ParseError> {
+	let mut lines = input.lines();
+	let mut numbers = |label: &str| {
+		let line = lines.next().or_parse_error(&input[input.len()..], format!("Expected `{label}`"))?;
+		line.strip_prefix(label).or_parse_error(line, format!("Expected `{label}`"))
+	};
+	Ok((numbers("Time:")?, numbers("Distance:")?))
+}
+
+/// Number written with spaces between its digits.
+fn kerned_number(text: &str) -> Result<u64, ParseError> {
+	text.replace(char::is_whitespace, "").parse().map_err(|err| ParseError::new(text, err))
+}
+
+impl Race {
+	fn ways_to_beat_distance(&self) -> usize {
+		// The formula to whether it is beaten is - x^2 + time * k - record > 0,
+		// so the pq-formula tells us that the roots are at:
+		// x_1,2 = time/2 +- sqrt((time/2)^2 - record).
+		// We add a small epsilon to make sure we are not at exactly 0, but higher.
+		let p1 = self.time as f64 / 2.0;
+		let p2 = ((self.time * self.time) as f64 / 4.0 - self.record as f64 - 0.1).sqrt();
+		let start = (p1 - p2).ceil() as usize;
+		let end = (p1 + p2).floor() as usize;
+		end - start + 1
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	const INPUT: &str = r#"
+		Time:      7  15   30
+		Distance:  9  40  200
+		"#;
+
+	#[test]
+	fn part1() -> Result<()> {
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(288));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(71503));
+
+		Ok(())
+	}
+}
@@ -1,29 +1,32 @@
 use std::{cmp::Ordering, collections::BTreeMap, str::FromStr};
 
-use anyhow::{bail, Context, Result};
+use anyhow::{bail, Result};
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::{self, OrParseError, ParseError};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let game = Game::<Card1>::from_str(input)?;
 
 		let total_winnings = game.total_winnings();
 
-		Ok(total_winnings.to_string())
+		Ok(total_winnings.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let game = Game::<Card2>::from_str(input)?;
 
 		let total_winnings = game.total_winnings();
 
-		Ok(total_winnings.to_string())
+		Ok(total_winnings.into())
 	}
 }
 
+const CARD_EXPECTED: &str = "Expected a card: `2` to `9`, `T`, `J`, `Q`, `K` or `A`";
+
 #[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy)]
 enum Card1 {
 	Two,
@@ -41,9 +44,11 @@ enum Card1 {
 	Ace,
 }
 
-impl From<char> for Card1 {
-	fn from(c: char) -> Self {
-		match c {
+impl TryFrom<char> for Card1 {
+	type Error = anyhow::Error;
+
+	fn try_from(c: char) -> Result<Self> {
+		Ok(match c {
 			'2' => Self::Two,
 			'3' => Self::Three,
 			'4' => Self::Four,
@@ -57,8 +62,8 @@ impl From<char> for Card1 {
 			'Q' => Self::Queen,
 			'K' => Self::King,
 			'A' => Self::Ace,
-			_ => panic!("Invalid character for card: {c}"),
-		}
+			_ => bail!(CARD_EXPECTED),
+		})
 	}
 }
 
@@ -79,9 +84,11 @@ enum Card2 {
 	Ace,
 }
 
-impl From<char> for Card2 {
-	fn from(c: char) -> Self {
-		match c {
+impl TryFrom<char> for Card2 {
+	type Error = anyhow::Error;
+
+	fn try_from(c: char) -> Result<Self> {
+		Ok(match c {
 			'J' => Self::Joker,
 			'2' => Self::Two,
 			'3' => Self::Three,
@@ -95,8 +102,8 @@ impl From<char> for Card2 {
 			'Q' => Self::Queen,
 			'K' => Self::King,
 			'A' => Self::Ace,
-			_ => panic!("Invalid character for card: {c}"),
-		}
+			_ => bail!(CARD_EXPECTED),
+		})
 	}
 }
 
@@ -199,15 +206,15 @@ struct Hand<Card> {
 
 impl<Card> FromStr for Hand<Card>
 where
-	Card: From<char> + Copy,
+	Card: TryFrom<char, Error = anyhow::Error> + Copy,
 	HandType: From<[Card; 5]>,
 {
 	type Err = anyhow::Error;
 
 	fn from_str(hand: &str) -> Result<Self> {
-		let cards = hand.chars().map(Card::from).collect::<Vec<_>>();
+		let cards = parse::each_char(hand).collect::<Result<Vec<Card>, _>>()?;
 		if cards.len() != 5 {
-			bail!("Hand is not exactly 5 cards");
+			bail!(ParseError::new(hand, "Expected exactly 5 cards"));
 		}
 
 		let cards = [cards[0], cards[1], cards[2], cards[3], cards[4]];
@@ -241,19 +248,19 @@ struct Game<Card> {
 
 impl<Card> FromStr for Game<Card>
 where
-	Card: From<char> + PartialEq + Eq + PartialOrd + Ord + Copy,
+	Card: TryFrom<char, Error = anyhow::Error> + PartialEq + Eq + PartialOrd + Ord + Copy,
 	HandType: From<[Card; 5]>,
 {
 	type Err = anyhow::Error;
 
 	fn from_str(input: &str) -> Result<Self> {
 		let bids = input
-			.trim()
 			.lines()
 			.map(|line| {
-				let (hand, bid) = line.trim().split_once(' ').context("Wrong line format")?;
+				let (hand, bid) =
+					line.split_once(' ').or_parse_error(line, "Expected a hand and a bid")?;
 				let hand = hand.parse::<Hand<Card>>()?;
-				let bid = bid.parse::<u64>()?;
+				let bid = parse::value(bid)?;
 				Ok::<_, anyhow::Error>((hand, bid))
 			})
 			.collect::<Result<_, _>>()?;
@@ -274,6 +281,7 @@ impl<Card> Game<Card> {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		32T3K 765
@@ -285,21 +293,30 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "6440");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(6440));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "5905");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(5905));
 
 		Ok(())
 	}
+
+	#[test]
+	fn malformed() {
+		let error = solve(&Day, Part::One, "32T3X 765").expect_err("invalid card");
+		assert_eq!(error.to_string(), format!("{CARD_EXPECTED} at line 1, column 5"));
+	}
 }
 
+/// Poker hand with the real ranking rules, which is not what the puzzle asks
+/// for.
+#[allow(dead_code)]
 #[derive(Debug, PartialEq, Eq, Clone, Copy)]
 enum CorrectHand {
 	HighCard { highest: Card1, high: Card1, middle: Card1, low: Card1, lowest: Card1 },
@@ -315,9 +332,9 @@ impl FromStr for CorrectHand {
 	type Err = anyhow::Error;
 
 	fn from_str(hand: &str) -> Result<Self> {
-		let mut cards = hand.chars().map(Card1::from).collect::<Vec<_>>();
+		let mut cards = parse::each_char(hand).collect::<Result<Vec<Card1>, _>>()?;
 		if cards.len() != 5 {
-			bail!("Hand is not exactly 5 cards");
+			bail!(ParseError::new(hand, "Expected exactly 5 cards"));
 		}
 
 		cards.sort();
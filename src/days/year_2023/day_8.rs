@@ -0,0 +1,269 @@
+use std::{collections::BTreeMap, str::FromStr};
+
+use ahash::AHashMap;
+use anyhow::{bail, Context, Result};
+use once_cell::sync::Lazy;
+use regex::Regex;
+
+use super::{Answer, AocDay};
+use crate::{
+	math::{self, Congruence},
+	parse::{self, OrParseError, ParseError},
+};
+
+pub struct Day;
+
+impl AocDay for Day {
+	fn part1(&self, input: &str) -> Result<Answer> {
+		let game = Game::from_str(input)?;
+
+		let steps = game.steps_from_to("AAA", "ZZZ")?;
+
+		Ok(steps.into())
+	}
+
+	fn part2(&self, input: &str) -> Result<Answer> {
+		let game = Game::from_str(input)?;
+
+		let steps = game.steps_part2()?;
+
+		Ok(steps.into())
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy, Hash)]
+enum Direction {
+	Left,
+	Right,
+}
+
+impl TryFrom<char> for Direction {
+	type Error = anyhow::Error;
+
+	fn try_from(c: char) -> Result<Self> {
+		match c {
+			'L' => Ok(Self::Left),
+			'R' => Ok(Self::Right),
+			_ => bail!("Expected a direction: `L` or `R`"),
+		}
+	}
+}
+
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+struct Either<T> {
+	left: T,
+	right: T,
+}
+
+impl<T> Either<T> {
+	fn get(&self, which: Direction) -> &T {
+		match which {
+			Direction::Left => &self.left,
+			Direction::Right => &self.right,
+		}
+	}
+}
+
+struct Map {
+	map: BTreeMap<String, Either<String>>,
+}
+
+impl FromStr for Map {
+	type Err = anyhow::Error;
+
+	fn from_str(map_str: &str) -> Result<Self> {
+		static REGEX: Lazy<Regex> =
+			Lazy::new(|| Regex::new(r"(.{3}) = \((.{3}), (.{3})\)").expect("create Regex"));
+
+		let map = map_str
+			.lines()
+			.map(|line| {
+				let captures =
+					REGEX.captures(line).or_parse_error(line, "Expected `AAA = (BBB, CCC)`")?;
+
+				let source = captures[1].to_owned();
+				let left = captures[2].to_owned();
+				let right = captures[3].to_owned();
+
+				Ok::<_, anyhow::Error>((source, Either { left, right }))
+			})
+			.collect::<Result<_, _>>()?;
+
+		Ok(Self { map })
+	}
+}
+
+impl Map {
+	fn next(&self, source: &str, direction: Direction) -> Option<&String> {
+		let either = self.map.get(source)?;
+		Some(either.get(direction))
+	}
+}
+
+struct Game {
+	sequence: Vec<Direction>,
+	map: Map,
+}
+
+impl FromStr for Game {
+	type Err = anyhow::Error;
+
+	fn from_str(input: &str) -> Result<Self> {
+		let (sequence_text, map) = input
+			.split_once("\n\n")
+			.or_parse_error(input, "Expected an empty line after the directions")?;
+
+		let sequence = parse::each_char(sequence_text).collect::<Result<Vec<_>, _>>()?;
+		if sequence.is_empty() {
+			bail!(ParseError::new(sequence_text, "Expected at least one direction"));
+		}
+		let map = map.parse()?;
+
+		Ok(Self { sequence, map })
+	}
+}
+
+impl Game {
+	fn steps_from_to(&self, source: &str, target: &str) -> Result<usize> {
+		let mut sequence = self.sequence.iter().copied().cycle();
+		let mut current = source;
+		let mut steps = 0;
+		while current != target {
+			let direction = sequence.next().context("get next direction")?;
+			current = self.map.next(current, direction).context("could not find node")?;
+			steps += 1;
+		}
+		Ok(steps)
+	}
+
+	/// Steps until all ghosts are on nodes ending in `Z` at the same time.
+	fn steps_part2(&self) -> Result<i128> {
+		let starts = self.map.map.keys().filter(|key| key.ends_with('A'));
+		let congruences = starts.map(|start| self.ghost_exits(start)).collect::<Result<Vec<_>>>()?;
+		if congruences.is_empty() {
+			bail!("No node ends in A");
+		}
+		let all_exited = congruences.iter().map(|(first, _)| *first).max().unwrap_or_default();
+		let solution = math::crt(congruences.into_iter().map(|(_, congruence)| congruence))?;
+		solution.smallest_from(all_exited).context("The number of steps overflows")
+	}
+
+	/// First step the ghost from `start` is on a node ending in `Z` and the
+	/// congruence of all such steps. Fails unless the ghost only reaches these
+	/// nodes once it walks in circles, and then at even intervals.
+	fn ghost_exits(&self, start: &str) -> Result<(i128, Congruence)> {
+		// The ghost is at a node and an index into the sequence, which repeat.
+		let mut seen = AHashMap::new();
+		let mut exits = Vec::new();
+		let mut current = start;
+		let mut directions = self.sequence.iter().copied().enumerate().cycle();
+		let (cycle_start, cycle_end) = loop {
+			let (index, direction) = directions.next().context("get next direction")?;
+			let steps = seen.len();
+			if let Some(&cycle_start) = seen.get(&(current, index)) {
+				break (cycle_start, steps);
+			}
+			seen.insert((current, index), steps);
+			if current.ends_with('Z') {
+				exits.push(steps);
+			}
+			current = self.map.next(current, direction).context("could not find node")?;
+		};
+
+		let Some(&first) = exits.first() else {
+			bail!("The ghost from {start} never reaches a node ending in Z");
+		};
+		if first < cycle_start {
+			bail!("The ghost from {start} reaches a node ending in Z before walking in circles");
+		}
+		let period = (cycle_end - cycle_start) / exits.len();
+		let even = exits.len() * period == cycle_end - cycle_start
+			&& exits.windows(2).all(|pair| pair[1] - pair[0] == period);
+		if !even {
+			bail!("The ghost from {start} reaches nodes ending in Z at uneven intervals");
+		}
+		Ok((first as i128, Congruence::new(first as i128, period as i128)))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::testing::{solve, Part};
+
+	#[test]
+	fn part1() -> Result<()> {
+		let input = r#"
+		RL
+
+		AAA = (BBB, CCC)
+		BBB = (DDD, EEE)
+		CCC = (ZZZ, GGG)
+		DDD = (DDD, DDD)
+		EEE = (EEE, EEE)
+		GGG = (GGG, GGG)
+		ZZZ = (ZZZ, ZZZ)
+		"#;
+
+		let result = solve(&Day, Part::One, input)?;
+		assert_eq!(result, Answer::Int(2));
+
+		let input = r#"
+		LLR
+
+		AAA = (BBB, BBB)
+		BBB = (AAA, ZZZ)
+		ZZZ = (ZZZ, ZZZ)
+		"#;
+
+		let result = solve(&Day, Part::One, input)?;
+		assert_eq!(result, Answer::Int(6));
+
+		Ok(())
+	}
+
+	#[test]
+	fn part2() -> Result<()> {
+		let input = r#"
+		LR
+
+		11A = (11B, XXX)
+		11B = (XXX, 11Z)
+		11Z = (11B, XXX)
+		22A = (22B, XXX)
+		22B = (22C, 22C)
+		22C = (22Z, 22Z)
+		22Z = (22B, 22B)
+		XXX = (XXX, XXX)
+		"#;
+
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(6));
+
+		// The ghosts first exit after 1 and 2 steps, then every 2 and 3 steps.
+		let input = r#"
+		L
+
+		11A = (11Z, 11Z)
+		11Z = (11B, 11B)
+		11B = (11Z, 11Z)
+		22A = (22B, 22B)
+		22B = (22Z, 22Z)
+		22Z = (22C, 22C)
+		22C = (22D, 22D)
+		22D = (22Z, 22Z)
+		"#;
+
+		let result = solve(&Day, Part::Two, input)?;
+		assert_eq!(result, Answer::Int(5));
+
+		Ok(())
+	}
+
+	#[test]
+	fn malformed() {
+		let error = solve(&Day, Part::One, "LRX\n\nAAA = (ZZZ, ZZZ)").expect_err("invalid direction");
+		assert_eq!(error.to_string(), "Expected a direction: `L` or `R` at line 1, column 3");
+		assert!(solve(&Day, Part::Two, "LR\n\n11A = (11B, 11B)").is_err());
+	}
+}
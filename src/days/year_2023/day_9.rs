@@ -1,26 +1,27 @@
 use std::str::FromStr;
 
-use anyhow::Result;
+use anyhow::{bail, Result};
 
-use super::AocDay;
+use super::{Answer, AocDay};
+use crate::parse::{self, ParseError};
 
 pub struct Day;
 
 impl AocDay for Day {
-	fn part1(&self, input: &str) -> Result<String> {
+	fn part1(&self, input: &str) -> Result<Answer> {
 		let mut histories = Histories::from_str(input)?;
 
 		let predictions: i64 = histories.predictions().into_iter().sum();
 
-		Ok(predictions.to_string())
+		Ok(predictions.into())
 	}
 
-	fn part2(&self, input: &str) -> Result<String> {
+	fn part2(&self, input: &str) -> Result<Answer> {
 		let histories = Histories::from_str(input)?;
 
 		let result: i64 = histories.backward_extrapolations().into_iter().sum();
 
-		Ok(result.to_string())
+		Ok(result.into())
 	}
 }
 
@@ -44,15 +45,15 @@ impl Histories {
 
 impl History {
 	fn add_prediction(&mut self) -> i64 {
-		let mut last_nums = vec![*self.history.last().unwrap()];
+		let mut last_nums = Vec::new();
 		let mut current_sequence = self.history.clone();
-		loop {
-			current_sequence =
-				current_sequence.windows(2).map(|window| window[1] - window[0]).collect();
-			last_nums.push(*current_sequence.last().unwrap());
+		while let Some(last_num) = current_sequence.last() {
+			last_nums.push(*last_num);
 			if current_sequence.iter().all(|i| *i == 0) {
 				break;
 			}
+			current_sequence =
+				current_sequence.windows(2).map(|window| window[1] - window[0]).collect();
 		}
 
 		let mut add = 0;
@@ -66,15 +67,15 @@ impl History {
 	}
 
 	fn backward_extrapolation(&self) -> i64 {
-		let mut first_nums = vec![*self.history.first().unwrap()];
+		let mut first_nums = Vec::new();
 		let mut current_sequence = self.history.clone();
-		loop {
-			current_sequence =
-				current_sequence.windows(2).map(|window| window[1] - window[0]).collect();
-			first_nums.push(*current_sequence.first().unwrap());
+		while let Some(first_num) = current_sequence.first() {
+			first_nums.push(*first_num);
 			if current_sequence.iter().all(|i| *i == 0) {
 				break;
 			}
+			current_sequence =
+				current_sequence.windows(2).map(|window| window[1] - window[0]).collect();
 		}
 
 		let mut sub = 0;
@@ -90,7 +91,7 @@ impl FromStr for Histories {
 	type Err = anyhow::Error;
 
 	fn from_str(input: &str) -> Result<Self> {
-		let histories = input.trim().lines().map(History::from_str).collect::<Result<_, _>>()?;
+		let histories = input.lines().map(History::from_str).collect::<Result<_, _>>()?;
 		Ok(Self(histories))
 	}
 }
@@ -99,7 +100,10 @@ impl FromStr for History {
 	type Err = anyhow::Error;
 
 	fn from_str(line: &str) -> Result<Self> {
-		let history = line.split_whitespace().map(i64::from_str).collect::<Result<_, _>>()?;
+		let history = line.split_whitespace().map(parse::value).collect::<Result<Vec<_>, _>>()?;
+		if history.is_empty() {
+			bail!(ParseError::new(line, "Expected at least one number"));
+		}
 		Ok(Self { history })
 	}
 }
@@ -107,6 +111,7 @@ impl FromStr for History {
 #[cfg(test)]
 mod tests {
 	use super::*;
+	use crate::days::testing::{solve, Part};
 
 	const INPUT: &str = r#"
 		0 3 6 9 12 15
@@ -116,16 +121,24 @@ mod tests {
 
 	#[test]
 	fn part1() -> Result<()> {
-		let result = Day.part1(INPUT)?;
-		assert_eq!(result.as_str(), "114");
+		let result = solve(&Day, Part::One, INPUT)?;
+		assert_eq!(result, Answer::Int(114));
 
 		Ok(())
 	}
 
 	#[test]
 	fn part2() -> Result<()> {
-		let result = Day.part2(INPUT)?;
-		assert_eq!(result.as_str(), "2");
+		let result = solve(&Day, Part::Two, INPUT)?;
+		assert_eq!(result, Answer::Int(2));
+
+		Ok(())
+	}
+
+	#[test]
+	fn single_number() -> Result<()> {
+		assert_eq!(solve(&Day, Part::One, "5\n1 2 3")?, Answer::Int(9));
+		assert_eq!(solve(&Day, Part::Two, "5\n1 2 3")?, Answer::Int(5));
 
 		Ok(())
 	}
@@ -0,0 +1,198 @@
+use std::ops::{Add, Sub};
+
+/// Cardinal direction, with up towards smaller `y`.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub enum Direction {
+	Up,
+	Right,
+	Down,
+	Left,
+}
+
+impl Direction {
+	/// All directions, clockwise from up.
+	pub const ALL: [Self; 4] = [Self::Up, Self::Right, Self::Down, Self::Left];
+
+	/// Direction after turning 90° counterclockwise.
+	pub fn turn_left(self) -> Self {
+		match self {
+			Self::Up => Self::Left,
+			Self::Right => Self::Up,
+			Self::Down => Self::Right,
+			Self::Left => Self::Down,
+		}
+	}
+
+	/// Direction after turning 90° clockwise.
+	pub fn turn_right(self) -> Self {
+		match self {
+			Self::Up => Self::Right,
+			Self::Right => Self::Down,
+			Self::Down => Self::Left,
+			Self::Left => Self::Up,
+		}
+	}
+
+	pub fn opposite(self) -> Self {
+		match self {
+			Self::Up => Self::Down,
+			Self::Right => Self::Left,
+			Self::Down => Self::Up,
+			Self::Left => Self::Right,
+		}
+	}
+
+	pub fn is_vertical(self) -> bool {
+		matches!(self, Self::Up | Self::Down)
+	}
+
+	/// Change of `x` and `y` of a step in this direction.
+	pub fn delta(self) -> (isize, isize) {
+		match self {
+			Self::Up => (0, -1),
+			Self::Right => (1, 0),
+			Self::Down => (0, 1),
+			Self::Left => (-1, 0),
+		}
+	}
+}
+
+/// Unsigned 2D point, like the position of a tile in a grid. `x` grows to the
+/// right and `y` downwards.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Position {
+	pub x: usize,
+	pub y: usize,
+}
+
+impl Position {
+	pub const fn new(x: usize, y: usize) -> Self {
+		Self { x, y }
+	}
+
+	/// Position one step in the direction, `None` if it would leave the
+	/// unsigned range.
+	pub fn step(self, direction: Direction) -> Option<Self> {
+		self.go(direction, 1)
+	}
+
+	/// Position `distance` steps in the direction, `None` if it would leave
+	/// the unsigned range.
+	pub fn go(self, direction: Direction, distance: usize) -> Option<Self> {
+		let distance = isize::try_from(distance).ok()?;
+		let (dx, dy) = direction.delta();
+		Some(Self {
+			x: self.x.checked_add_signed(dx.checked_mul(distance)?)?,
+			y: self.y.checked_add_signed(dy.checked_mul(distance)?)?,
+		})
+	}
+
+	pub fn manhattan_distance(self, other: Self) -> usize {
+		self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+	}
+}
+
+/// Signed 2D point, with the same axes as [`Position`].
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point {
+	pub x: isize,
+	pub y: isize,
+}
+
+impl Point {
+	pub const fn new(x: isize, y: isize) -> Self {
+		Self { x, y }
+	}
+
+	pub fn step(self, direction: Direction) -> Self {
+		self.go(direction, 1)
+	}
+
+	/// Point `distance` steps in the direction.
+	pub fn go(self, direction: Direction, distance: isize) -> Self {
+		let (dx, dy) = direction.delta();
+		Self { x: self.x + dx * distance, y: self.y + dy * distance }
+	}
+
+	pub fn manhattan_distance(self, other: Self) -> usize {
+		self.x.abs_diff(other.x) + self.y.abs_diff(other.y)
+	}
+}
+
+impl Add for Point {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self { x: self.x + rhs.x, y: self.y + rhs.y }
+	}
+}
+
+impl Sub for Point {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Self { x: self.x - rhs.x, y: self.y - rhs.y }
+	}
+}
+
+/// Signed 3D point.
+#[derive(Debug, Default, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash)]
+pub struct Point3 {
+	pub x: isize,
+	pub y: isize,
+	pub z: isize,
+}
+
+impl Point3 {
+	pub const fn new(x: isize, y: isize, z: isize) -> Self {
+		Self { x, y, z }
+	}
+
+	pub fn manhattan_distance(self, other: Self) -> usize {
+		self.x.abs_diff(other.x) + self.y.abs_diff(other.y) + self.z.abs_diff(other.z)
+	}
+}
+
+impl Add for Point3 {
+	type Output = Self;
+
+	fn add(self, rhs: Self) -> Self {
+		Self { x: self.x + rhs.x, y: self.y + rhs.y, z: self.z + rhs.z }
+	}
+}
+
+impl Sub for Point3 {
+	type Output = Self;
+
+	fn sub(self, rhs: Self) -> Self {
+		Self { x: self.x - rhs.x, y: self.y - rhs.y, z: self.z - rhs.z }
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn movement() {
+		for direction in Direction::ALL {
+			assert_eq!(direction.turn_left().turn_right(), direction);
+			assert_eq!(direction.turn_right().turn_right(), direction.opposite());
+		}
+
+		let position = Position::new(2, 0);
+		assert_eq!(position.step(Direction::Right), Some(Position::new(3, 0)));
+		assert_eq!(position.step(Direction::Up), None);
+		assert_eq!(position.go(Direction::Left, 2), Some(Position::new(0, 0)));
+		assert_eq!(position.go(Direction::Left, 3), None);
+		assert_eq!(position.manhattan_distance(Position::new(0, 3)), 5);
+
+		let point = Point::new(0, 0).go(Direction::Up, 3).step(Direction::Left);
+		assert_eq!(point, Point::new(-1, -3));
+		assert_eq!(point - Point::new(1, 1), Point::new(-2, -4));
+		assert_eq!(point.manhattan_distance(Point::new(1, 1)), 6);
+
+		let point = Point3::new(1, -2, 3) + Point3::new(1, 1, 1);
+		assert_eq!(point.manhattan_distance(Point3::default()), 7);
+	}
+}
@@ -0,0 +1,251 @@
+use std::{
+	fmt::Display,
+	ops::{Index, IndexMut},
+};
+
+use crate::{
+	geometry::{Direction, Position},
+	parse::{self, ParseError},
+};
+
+/// Offsets of the 8 neighbours including diagonals, clockwise from up.
+const NEIGHBOURS_8: [(isize, isize); 8] =
+	[(0, -1), (1, -1), (1, 0), (1, 1), (0, 1), (-1, 1), (-1, 0), (-1, -1)];
+
+/// Rectangular grid of tiles, stored row by row.
+#[derive(Debug, PartialEq, Eq, Clone, Hash)]
+pub struct Grid<T> {
+	tiles: Vec<T>,
+	width: usize,
+	height: usize,
+}
+
+impl<T> Grid<T> {
+	/// Grid of the given size with the tile of each position.
+	pub fn from_fn(width: usize, height: usize, mut tile: impl FnMut(Position) -> T) -> Self {
+		let tiles = (0..height)
+			.flat_map(|y| (0..width).map(move |x| Position { x, y }))
+			.map(&mut tile)
+			.collect();
+		Self { tiles, width, height }
+	}
+
+	/// Grid of the given size filled with the tile.
+	pub fn new(width: usize, height: usize, tile: T) -> Self
+	where
+		T: Clone,
+	{
+		Self { tiles: vec![tile; width * height], width, height }
+	}
+
+	/// Parse a grid of one character per tile, see [`Grid::parse_with`].
+	pub fn parse(input: &str) -> Result<Self, ParseError>
+	where
+		T: TryFrom<char>,
+		T::Error: Display,
+	{
+		Self::parse_with(input, T::try_from)
+	}
+
+	/// Parse a grid mapping each character to a tile, failing with a
+	/// [`ParseError`] about the character if the mapping fails or about a line
+	/// if the grid is not rectangular.
+	pub fn parse_with<E: Display>(
+		input: &str,
+		mut tile: impl FnMut(char) -> Result<T, E>,
+	) -> Result<Self, ParseError> {
+		let lines = parse::grid_lines(input)?;
+		let width = lines[0].chars().count();
+		let mut tiles = Vec::with_capacity(width * lines.len());
+		for line in &lines {
+			if line.chars().count() != width {
+				return Err(ParseError::new(line, format!("Expected a line of length {width}")));
+			}
+			for (c, text) in parse::chars(line) {
+				tiles.push(tile(c).map_err(|err| ParseError::new(text, err))?);
+			}
+		}
+		Ok(Self { tiles, width, height: lines.len() })
+	}
+
+	pub fn width(&self) -> usize {
+		self.width
+	}
+
+	pub fn height(&self) -> usize {
+		self.height
+	}
+
+	pub fn in_bounds(&self, position: Position) -> bool {
+		position.x < self.width && position.y < self.height
+	}
+
+	/// Tile at the position, `None` if it is outside of the grid.
+	pub fn get(&self, position: Position) -> Option<&T> {
+		self.index(position).map(|index| &self.tiles[index])
+	}
+
+	pub fn get_mut(&mut self, position: Position) -> Option<&mut T> {
+		self.index(position).map(|index| &mut self.tiles[index])
+	}
+
+	fn index(&self, position: Position) -> Option<usize> {
+		self.in_bounds(position).then(|| position.y * self.width + position.x)
+	}
+
+	/// Positions row by row.
+	pub fn positions(&self) -> impl Iterator<Item = Position> {
+		let width = self.width;
+		(0..self.height).flat_map(move |y| (0..width).map(move |x| Position { x, y }))
+	}
+
+	/// Tiles with their positions, row by row.
+	pub fn iter(&self) -> impl Iterator<Item = (Position, &T)> {
+		self.positions().zip(&self.tiles)
+	}
+
+	/// Position of the first tile, row by row, matching the predicate.
+	pub fn position(&self, mut predicate: impl FnMut(&T) -> bool) -> Option<Position> {
+		self.iter().find_map(|(position, tile)| predicate(tile).then_some(position))
+	}
+
+	pub fn row(&self, y: usize) -> Option<&[T]> {
+		(y < self.height).then(|| &self.tiles[y * self.width..(y + 1) * self.width])
+	}
+
+	/// Rows from top to bottom.
+	pub fn rows(&self) -> impl Iterator<Item = &[T]> {
+		(0..self.height).map(|y| &self.tiles[y * self.width..(y + 1) * self.width])
+	}
+
+	/// Tiles of the column from top to bottom, none if it is outside of the
+	/// grid.
+	pub fn column(&self, x: usize) -> impl Iterator<Item = &T> {
+		(0..self.height).filter_map(move |y| self.get(Position { x, y }))
+	}
+
+	/// Columns from left to right.
+	pub fn columns(&self) -> impl Iterator<Item = impl Iterator<Item = &T>> {
+		(0..self.width).map(|x| self.column(x))
+	}
+
+	/// Grid mirrored along its main diagonal, rows become columns.
+	pub fn transpose(&self) -> Self
+	where
+		T: Clone,
+	{
+		Self::from_fn(self.height, self.width, |Position { x, y }| {
+			self[Position { x: y, y: x }].clone()
+		})
+	}
+
+	/// Grid turned by 90° clockwise, the left column becomes the top row.
+	pub fn rotate_clockwise(&self) -> Self
+	where
+		T: Clone,
+	{
+		Self::from_fn(self.height, self.width, |Position { x, y }| {
+			self[Position { x: y, y: self.height - 1 - x }].clone()
+		})
+	}
+
+	/// Grid turned by 90° counterclockwise, the top row becomes the left
+	/// column.
+	pub fn rotate_counterclockwise(&self) -> Self
+	where
+		T: Clone,
+	{
+		Self::from_fn(self.height, self.width, |Position { x, y }| {
+			self[Position { x: self.width - 1 - y, y: x }].clone()
+		})
+	}
+
+	/// Position one step in the direction, `None` if it is outside of the grid.
+	pub fn step(&self, position: Position, direction: Direction) -> Option<Position> {
+		position.step(direction).filter(|&position| self.in_bounds(position))
+	}
+
+	/// Positions of the up to 4 horizontal and vertical neighbours in the grid,
+	/// clockwise from up.
+	pub fn neighbours4(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+		Direction::ALL.into_iter().filter_map(move |direction| self.step(position, direction))
+	}
+
+	/// Positions of the up to 8 neighbours in the grid, including diagonals.
+	pub fn neighbours8(&self, position: Position) -> impl Iterator<Item = Position> + '_ {
+		NEIGHBOURS_8.into_iter().filter_map(move |offset| self.offset(position, offset))
+	}
+
+	fn offset(&self, position: Position, (dx, dy): (isize, isize)) -> Option<Position> {
+		let position = Position {
+			x: position.x.checked_add_signed(dx)?,
+			y: position.y.checked_add_signed(dy)?,
+		};
+		self.in_bounds(position).then_some(position)
+	}
+}
+
+impl<T> Index<Position> for Grid<T> {
+	type Output = T;
+
+	fn index(&self, position: Position) -> &T {
+		self.get(position).unwrap_or_else(|| {
+			panic!("{position:?} is outside of the {}x{} grid", self.width, self.height)
+		})
+	}
+}
+
+impl<T> IndexMut<Position> for Grid<T> {
+	fn index_mut(&mut self, position: Position) -> &mut T {
+		let (width, height) = (self.width, self.height);
+		self.get_mut(position)
+			.unwrap_or_else(|| panic!("{position:?} is outside of the {width}x{height} grid"))
+	}
+}
+
+impl<T: Display> Display for Grid<T> {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		for row in self.rows() {
+			for tile in row {
+				tile.fmt(f)?;
+			}
+			f.write_str("\n")?;
+		}
+		Ok(())
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn grid() -> Result<(), ParseError> {
+		let grid = Grid::<char>::parse("ab\ncd\nef\n")?;
+		assert_eq!((grid.width(), grid.height()), (2, 3));
+		assert_eq!(grid.get(Position::new(1, 2)), Some(&'f'));
+		assert_eq!(grid.get(Position::new(2, 0)), None);
+		assert_eq!(grid.position(|&c| c == 'd'), Some(Position::new(1, 1)));
+		assert_eq!(grid.rows().collect::<Vec<_>>(), [['a', 'b'], ['c', 'd'], ['e', 'f']]);
+		assert_eq!(grid.column(1).collect::<String>(), "bdf");
+		assert_eq!(grid.column(2).count(), 0);
+
+		assert_eq!(grid.transpose().to_string(), "ace\nbdf\n");
+		assert_eq!(grid.rotate_clockwise().to_string(), "eca\nfdb\n");
+		assert_eq!(grid.rotate_counterclockwise().to_string(), "bdf\nace\n");
+		assert_eq!(grid.rotate_clockwise().rotate_counterclockwise(), grid);
+
+		let corner = grid.neighbours4(Position::new(0, 0)).collect::<Vec<_>>();
+		assert_eq!(corner, [Position::new(1, 0), Position::new(0, 1)]);
+		assert_eq!(grid.neighbours8(Position::new(0, 1)).count(), 5);
+		assert_eq!(grid.step(Position::new(1, 2), Direction::Down), None);
+
+		let error = Grid::<u8>::parse_with("12\n3x\n", |c| {
+			c.to_digit(10).map(|digit| digit as u8).ok_or("Expected a digit")
+		})
+		.map(|_| ())
+		.expect_err("not a digit");
+		assert_eq!(error.to_string(), "Expected a digit: `x`");
+		Ok(())
+	}
+}
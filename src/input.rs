@@ -0,0 +1,117 @@
+use anyhow::Result;
+
+use crate::parse::{self, Location, ParseError};
+
+/// Where the lines of a normalized input come from, to report the locations
+/// of parse errors in the raw input the user sees.
+#[derive(Debug, Clone, Default)]
+pub struct SourceMap {
+	/// Raw input without a byte order mark.
+	raw: String,
+	/// Empty lines removed before the first line.
+	skipped_lines: usize,
+	/// Characters of indentation removed from every non-empty line.
+	indentation: usize,
+}
+
+impl SourceMap {
+	/// Move the location of a [`ParseError`] in the error from the normalized
+	/// to the raw input.
+	pub fn relocate(&self, mut err: anyhow::Error) -> anyhow::Error {
+		if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+			parse_error.map_location(|location| self.raw_location(location));
+		}
+		err
+	}
+
+	fn raw_location(&self, location: Location) -> Location {
+		let line = location.line + self.skipped_lines;
+		let shift = if location.source.is_empty() { 0 } else { self.indentation };
+		Location {
+			line,
+			columns: location.columns.start + shift..location.columns.end + shift,
+			source: self.raw.lines().nth(line - 1).map_or(location.source, str::to_owned),
+		}
+	}
+}
+
+/// Normalize an input and, for days that only accept ASCII, reject any other
+/// character. Errors are located in the raw input.
+pub fn prepare(input: &str, ascii_only: bool) -> Result<(String, SourceMap)> {
+	let (input, source_map) = normalize(input);
+	if ascii_only {
+		if let Some((_, text)) = parse::chars(&input).find(|(c, _)| !c.is_ascii()) {
+			let mut error = ParseError::new(text, "Expected only ASCII characters");
+			error.locate(&input);
+			return Err(source_map.relocate(error.into()));
+		}
+	}
+	Ok((input, source_map))
+}
+
+/// Normalize an input so days do not have to care about how it was written:
+/// strip a byte order mark, use `\n` line endings, remove trailing whitespace,
+/// leading and trailing empty lines and the indentation common to all lines.
+/// Every line ends with `\n`. Returns the map back to the raw input as well.
+pub fn normalize(input: &str) -> (String, SourceMap) {
+	let input = input.strip_prefix('\u{feff}').unwrap_or(input);
+	let lines = input.lines().map(str::trim_end).collect::<Vec<_>>();
+	let start = lines.iter().position(|line| !line.is_empty()).unwrap_or(lines.len());
+	let end = lines.iter().rposition(|line| !line.is_empty()).map_or(start, |end| end + 1);
+	let lines = &lines[start..end];
+
+	let indentation = lines
+		.iter()
+		.filter(|line| !line.is_empty())
+		.map(|line| &line[..line.len() - line.trim_start().len()])
+		.reduce(|common, indentation| {
+			let length = common
+				.char_indices()
+				.zip(indentation.chars())
+				.find(|((_, a), b)| a != b)
+				.map_or(common.len().min(indentation.len()), |((i, _), _)| i);
+			&common[..length]
+		})
+		.unwrap_or_default();
+
+	let mut normalized = String::with_capacity(input.len());
+	for line in lines {
+		normalized.push_str(line.strip_prefix(indentation).unwrap_or(line));
+		normalized.push('\n');
+	}
+	let source_map = SourceMap {
+		raw: input.to_owned(),
+		skipped_lines: start,
+		indentation: indentation.chars().count(),
+	};
+	(normalized, source_map)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn normalization() {
+		assert_eq!(normalize("\u{feff}a\r\nb  \r\n").0, "a\nb\n");
+		assert_eq!(normalize("\n\t\t#.#\n\t\t  .#.\n\n\t\t#.# \n\t\t").0, "#.#\n  .#.\n\n#.#\n");
+		assert_eq!(normalize("  a\n\tb\n").0, "  a\n\tb\n");
+		assert_eq!(normalize("x\n\n\ny").0, "x\n\n\ny\n");
+		assert_eq!(normalize(" \n\t\n").0, "");
+
+		assert_eq!(
+			prepare("  abc\r\n", true).ok().map(|(input, _)| input),
+			Some("abc\n".to_owned())
+		);
+		let error = prepare("ab\ncdé", true).expect_err("non-ASCII input");
+		assert_eq!(error.to_string(), "Expected only ASCII characters at line 2, column 3");
+		let error = prepare("\r\n\t\tab\r\n\n\t\tcdé\r\n", true).expect_err("non-ASCII input");
+		assert_eq!(error.to_string(), "Expected only ASCII characters at line 4, column 5");
+		let snippet = error.downcast_ref::<ParseError>().and_then(ParseError::snippet);
+		assert_eq!(
+			snippet.as_deref(),
+			Some("  |\n4 | \t\tcdé\n  | \t\t  ^ Expected only ASCII characters\n")
+		);
+		assert!(prepare("é", false).is_ok());
+	}
+}
@@ -1,39 +1,299 @@
+mod alloc;
+mod answer;
+mod answers;
+mod api;
+mod bench;
+mod context;
+mod crash;
+mod cycle;
 mod days;
+// The helpers for the days in geometry, grid, math and search are kept whole,
+// although no day uses all of them yet.
+#[allow(dead_code)]
+mod geometry;
+#[allow(dead_code)]
+mod grid;
+mod input;
+#[allow(dead_code)]
+mod math;
+mod parse;
+mod report;
+mod runner;
+mod scaffold;
+#[allow(dead_code)]
+mod search;
+mod selection;
+mod serve;
+mod watch;
 
-use std::time::Instant;
+use std::{io::IsTerminal, net::SocketAddr, num::NonZeroUsize, path::PathBuf, time::Duration};
 
-use anyhow::{bail, Context, Result};
-use clap::{value_parser, Parser};
+use anyhow::{bail, Result};
+use clap::{value_parser, Args, Parser, Subcommand};
+
+pub use self::{
+	alloc::AllocStats,
+	answer::Answer,
+	api::{implemented_days, puzzle, solve, SolveError},
+	context::{Cancelled, Progress, RunContext},
+	days::{ParsedInput, Puzzle},
+	parse::{Location, ParseError},
+	runner::Part,
+};
+use self::{
+	answers::Answers,
+	bench::BenchOptions,
+	report::Format,
+	runner::{InputSets, RunOptions},
+	selection::{DaySelection, DEFAULT_YEAR},
+	serve::ServeOptions,
+};
 
 #[derive(Debug, Parser)]
+#[command(args_conflicts_with_subcommands = true)]
 pub struct Cli {
-	#[arg(value_parser = value_parser!(u8).range(1..=25))]
-	day: u8,
+	#[command(subcommand)]
+	command: Option<Command>,
+	#[command(flatten)]
+	run: RunArgs,
+}
+
+#[derive(Debug, Subcommand)]
+enum Command {
+	/// Compare the answers of the selected days with the answers file.
+	Verify(VerifyArgs),
+	/// Benchmark the selected days with repeated runs.
+	Bench(BenchArgs),
+	/// Serve an HTTP API on localhost that lists the days and solves posted
+	/// inputs.
+	Serve(ServeArgs),
+	/// Create the module and input file of a new day from the template.
+	New {
+		/// Year of the new day.
+		#[arg(long, default_value_t = DEFAULT_YEAR, value_parser = selection::parse_year)]
+		year: u16,
+		#[arg(value_parser = value_parser!(u8).range(1..=25))]
+		day: u8,
+	},
+}
+
+// Which days and parts to run.
+#[derive(Debug, Args)]
+struct SelectArgs {
+	/// Days to run: `all`, a single day, a list like `1,5,7`, a range like
+	/// `10..=20` or a combination like `1,10..=20`.
+	#[arg(default_value = "all")]
+	days: DaySelection,
+	/// Year of the days.
+	#[arg(long, default_value_t = DEFAULT_YEAR, value_parser = selection::parse_year)]
+	year: u16,
+	/// Only run this part.
+	#[arg(short, long)]
+	part: Option<Part>,
+}
+
+// How the selected days are run.
+#[derive(Debug, Args)]
+struct ExecArgs {
+	/// Number of days to run concurrently, defaults to the number of CPUs.
+	/// Always 1 with the `alloc-stats` feature.
+	#[arg(short, long)]
+	jobs: Option<usize>,
+	/// Give up on a part after it ran this long, e.g. `10s`. The part is asked
+	/// to stop, but keeps running in the background until it does.
+	#[arg(long, value_parser = bench::parse_duration)]
+	timeout: Option<Duration>,
+}
+
+#[derive(Debug, Args)]
+struct RunArgs {
+	#[command(flatten)]
+	select: SelectArgs,
+	#[command(flatten)]
+	exec: ExecArgs,
+	/// Read the puzzle input from this file instead of
+	/// `./inputs/{year}/day_{day}.txt`. Use `-` to read from stdin.
+	#[arg(short, long)]
+	input: Option<PathBuf>,
+	/// Output format of the results.
+	#[arg(long, value_enum, default_value_t)]
+	format: Format,
+	/// Run on the input set `./inputs/{year}/day_{day}/<NAME>.txt` instead of
+	/// the default input.
+	#[arg(long, value_name = "NAME", conflicts_with = "input")]
+	input_set: Option<String>,
+	/// Run on the default input and every input set of each day.
+	#[arg(long, conflicts_with_all = ["input", "input_set", "watch"])]
+	all_inputs: bool,
+	/// Keep running and re-run a day whenever its input file changes, showing
+	/// how the answers changed.
+	#[arg(long, conflicts_with = "format")]
+	watch: bool,
+}
+
+#[derive(Debug, Args)]
+struct VerifyArgs {
+	#[command(flatten)]
+	select: SelectArgs,
+	#[command(flatten)]
+	exec: ExecArgs,
+	/// File with the expected answers.
+	#[arg(long, default_value = answers::DEFAULT_PATH)]
+	answers: PathBuf,
+	/// Write the current answers into the answers file instead of comparing.
+	#[arg(long)]
+	record: bool,
+}
+
+#[derive(Debug, Args)]
+struct BenchArgs {
+	#[command(flatten)]
+	select: SelectArgs,
+	/// Time to run each step before measuring, e.g. `500ms`.
+	#[arg(long, default_value = "500ms", value_parser = bench::parse_duration)]
+	warmup: Duration,
+	/// Time to spend measuring each step, e.g. `3s`.
+	#[arg(long, default_value = "3s", value_parser = bench::parse_duration)]
+	budget: Duration,
+	/// Measure exactly this many runs of each step instead of using the time
+	/// budget.
+	#[arg(short = 'n', long, conflicts_with = "budget")]
+	iterations: Option<NonZeroUsize>,
+}
+
+#[derive(Debug, Args)]
+struct ServeArgs {
+	/// Loopback address to listen on.
+	#[arg(long, default_value = "127.0.0.1:3000")]
+	address: SocketAddr,
+	/// Reject inputs larger than this many bytes.
+	#[arg(long, default_value_t = 1 << 20)]
+	max_input_size: usize,
+	/// Give up on a request that did not arrive, or was not solved, within
+	/// this long, e.g. `10s`.
+	#[arg(long, default_value = "10s", value_parser = bench::parse_duration)]
+	timeout: Duration,
+	/// Reject requests while this many inputs are being solved, counting timed
+	/// out ones that are still running.
+	#[arg(long, default_value_t = NonZeroUsize::new(4).expect("not zero"))]
+	max_solves: NonZeroUsize,
 }
 
 impl Cli {
 	pub fn run(self) -> Result<()> {
-		if let Some(day) = days::DAYS.get(&self.day) {
-			let input = fetch_input(self.day)?;
-
-			let time = Instant::now();
-			let result = day.part1(&input)?;
-			println!("Part 1 ({:?}): {result}", time.elapsed());
-
-			let time = Instant::now();
-			let result = day.part2(&input)?;
-			println!("Part 2 ({:?}): {result}", time.elapsed());
-		} else {
-			bail!("This day is not implemented");
+		match self.command {
+			None => self.run.run(),
+			Some(Command::Verify(verify)) => verify.run(),
+			Some(Command::Bench(bench)) => bench.run(),
+			Some(Command::Serve(args)) => {
+				let options = ServeOptions {
+					max_input_size: args.max_input_size,
+					timeout: args.timeout,
+					max_solves: args.max_solves.get(),
+				};
+				serve::serve(args.address, options)
+			}
+			Some(Command::New { year, day }) => scaffold::new_day(year, day),
+		}
+	}
+}
+
+impl ExecArgs {
+	/// Options to run the given part, drawing progress if stderr is a terminal.
+	fn options(
+		&self,
+		part: Option<Part>,
+		input: Option<PathBuf>,
+		input_sets: InputSets,
+	) -> RunOptions {
+		RunOptions {
+			part,
+			input,
+			input_sets,
+			jobs: self.jobs,
+			timeout: self.timeout,
+			progress: std::io::stderr().is_terminal(),
+		}
+	}
+}
+
+impl RunArgs {
+	fn run(self) -> Result<()> {
+		let input_sets = match self.input_set {
+			Some(name) => InputSets::Named(name),
+			None if self.all_inputs => InputSets::All,
+			None => InputSets::Default,
+		};
+		let options = self.exec.options(self.select.part, self.input, input_sets);
+		if self.watch {
+			return watch::watch(self.select.year, &self.select.days, &options);
+		}
+		let reports = runner::run_days(self.select.year, &self.select.days, &options)?;
+		print!("{}", report::render(&reports, self.format));
+
+		let failed = report::num_failed(&reports);
+		if failed > 0 {
+			bail!("{failed} part(s) failed");
+		}
+		Ok(())
+	}
+}
+
+impl VerifyArgs {
+	fn run(self) -> Result<()> {
+		let options = self.exec.options(self.select.part, None, InputSets::Default);
+		let reports = runner::run_days(self.select.year, &self.select.days, &options)?;
+		let mut answers = Answers::load(&self.answers)?;
+
+		if self.record {
+			let mut recorded = 0;
+			for report in &reports {
+				for (part, part_report) in report.parts() {
+					if let Ok(answer) = &part_report.answer {
+						answers.insert(report.year, report.day, part, answer.clone());
+						recorded += 1;
+					}
+				}
+			}
+			answers.save(&self.answers)?;
+			println!("Recorded {recorded} answer(s) to `{}`", self.answers.display());
+
+			let failed = report::num_failed(&reports);
+			if failed > 0 {
+				bail!("{failed} part(s) failed and were not recorded");
+			}
+			return Ok(());
+		}
+
+		let (listing, failed) = answers.check(&reports);
+		print!("{listing}");
+		if failed > 0 {
+			bail!("{failed} part(s) did not match the expected answers");
 		}
 		Ok(())
 	}
 }
 
-fn fetch_input(day: u8) -> Result<String> {
-	let file = format!("./inputs/day_{day}.txt");
-	let input = std::fs::read_to_string(&file).context(format!("Could not read file `{file}`"))?;
-	Ok(input)
+impl BenchArgs {
+	fn run(self) -> Result<()> {
+		let options = BenchOptions {
+			part: self.select.part,
+			warmup: self.warmup,
+			budget: self.budget,
+			iterations: self.iterations,
+		};
+		let reports = bench::bench_days(self.select.year, &self.select.days, &options)?;
+		print!("{}", bench::bench_table(&reports));
+
+		let failed = reports
+			.iter()
+			.filter(|report| matches!(report.outcome, bench::BenchOutcome::Failed { .. }))
+			.count();
+		if failed > 0 {
+			bail!("{failed} step(s) failed");
+		}
+		Ok(())
+	}
 }
 
 #[cfg(test)]
@@ -46,4 +306,10 @@ mod tests {
 	fn cli() {
 		Cli::command().debug_assert();
 	}
+
+	#[test]
+	fn bench_needs_a_run() {
+		assert!(Cli::try_parse_from(["aoc", "bench", "6", "-n", "0", "--warmup", "0s"]).is_err());
+		assert!(Cli::try_parse_from(["aoc", "bench", "6", "-n", "1", "--warmup", "0s"]).is_ok());
+	}
 }
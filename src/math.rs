@@ -0,0 +1,179 @@
+use std::{
+	fmt::Display,
+	ops::{Div, Rem},
+};
+
+use anyhow::{bail, ensure, Context, Result};
+
+/// Unsigned integers supported by [`gcd`] and [`lcm`].
+pub trait Unsigned: Copy + Eq + Div<Output = Self> + Rem<Output = Self> {
+	const ZERO: Self;
+
+	fn checked_mul(self, rhs: Self) -> Option<Self>;
+}
+
+macro_rules! impl_unsigned {
+	($($t:ty),*) => {
+		$(impl Unsigned for $t {
+			const ZERO: Self = 0;
+
+			fn checked_mul(self, rhs: Self) -> Option<Self> {
+				<$t>::checked_mul(self, rhs)
+			}
+		})*
+	};
+}
+
+impl_unsigned!(u64, u128, usize);
+
+/// Greatest common divisor, 0 only if both are 0.
+pub fn gcd<T: Unsigned>(mut a: T, mut b: T) -> T {
+	while b != T::ZERO {
+		(a, b) = (b, a % b);
+	}
+	a
+}
+
+/// Least common multiple, `None` if it overflows.
+pub fn lcm<T: Unsigned>(a: T, b: T) -> Option<T> {
+	if a == T::ZERO || b == T::ZERO {
+		return Some(T::ZERO);
+	}
+	(a / gcd(a, b)).checked_mul(b)
+}
+
+/// Extended Euclidean algorithm: the non-negative gcd `g` of `a` and `b` with
+/// `x` and `y` such that `a * x + b * y == g`.
+pub fn extended_gcd(a: i128, b: i128) -> (i128, i128, i128) {
+	let (mut old_r, mut r) = (a, b);
+	let (mut old_x, mut x) = (1, 0);
+	let (mut old_y, mut y) = (0, 1);
+	while r != 0 {
+		let quotient = old_r / r;
+		(old_r, r) = (r, old_r - quotient * r);
+		(old_x, x) = (x, old_x - quotient * x);
+		(old_y, y) = (y, old_y - quotient * y);
+	}
+	if old_r < 0 {
+		(-old_r, -old_x, -old_y)
+	} else {
+		(old_r, old_x, old_y)
+	}
+}
+
+/// Inverse of `a` modulo the positive modulus, `None` if they are not coprime.
+pub fn mod_inverse(a: i128, modulus: i128) -> Option<i128> {
+	if modulus <= 0 {
+		return None;
+	}
+	let (g, x, _) = extended_gcd(a.rem_euclid(modulus), modulus);
+	(g == 1).then(|| x.rem_euclid(modulus))
+}
+
+/// Congruence `x ≡ residue (mod modulus)`.
+#[derive(Debug, PartialEq, Eq, Clone, Copy)]
+pub struct Congruence {
+	pub residue: i128,
+	pub modulus: i128,
+}
+
+impl Congruence {
+	/// Congruence with the residue reduced into `0..modulus`, the modulus must
+	/// be positive.
+	pub fn new(residue: i128, modulus: i128) -> Self {
+		assert!(modulus > 0, "modulus {modulus} is not positive");
+		Self { residue: residue.rem_euclid(modulus), modulus }
+	}
+
+	/// Smallest solution that is at least `min`, `None` if it overflows.
+	pub fn smallest_from(self, min: i128) -> Option<i128> {
+		let residue = self.residue.rem_euclid(self.modulus);
+		let periods =
+			min.checked_sub(residue)?.max(0).checked_add(self.modulus - 1)? / self.modulus;
+		periods.checked_mul(self.modulus)?.checked_add(residue)
+	}
+}
+
+impl Display for Congruence {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "x ≡ {} (mod {})", self.residue, self.modulus)
+	}
+}
+
+/// Solve the system of congruences with the Chinese remainder theorem. The
+/// moduli do not have to be coprime, the solution is modulo their least common
+/// multiple. Fails if the congruences contradict each other or the numbers
+/// overflow.
+pub fn crt(congruences: impl IntoIterator<Item = Congruence>) -> Result<Congruence> {
+	let mut solution = Congruence::new(0, 1);
+	for congruence in congruences {
+		ensure!(congruence.modulus > 0, "{congruence} has no positive modulus");
+		let (g, inverse, _) = extended_gcd(solution.modulus, congruence.modulus);
+		let difference = congruence.residue.checked_sub(solution.residue).context("overflow")?;
+		if difference % g != 0 {
+			bail!("{congruence} contradicts {solution}");
+		}
+
+		// Solve `solution.modulus * k ≡ difference (mod congruence.modulus)`.
+		let reduced = congruence.modulus / g;
+		let k = (difference / g)
+			.rem_euclid(reduced)
+			.checked_mul(inverse.rem_euclid(reduced))
+			.context("overflow")?
+			% reduced;
+		let modulus = (solution.modulus / g)
+			.checked_mul(congruence.modulus)
+			.with_context(|| format!("The modulus of {congruence} and {solution} overflows"))?;
+		let residue = solution
+			.modulus
+			.checked_mul(k)
+			.and_then(|offset| offset.checked_add(solution.residue))
+			.context("overflow")?;
+		solution = Congruence::new(residue, modulus);
+	}
+	Ok(solution)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn divisors() {
+		assert_eq!(gcd(12u64, 18), 6);
+		assert_eq!(gcd(0u64, 7), 7);
+		assert_eq!(lcm(4u64, 6), Some(12));
+		assert_eq!(lcm(0usize, 6), Some(0));
+		assert_eq!(lcm(u64::MAX, u64::MAX - 1), None);
+		assert_eq!(
+			lcm(u64::MAX as u128, u64::MAX as u128 - 1),
+			Some(u64::MAX as u128 * (u64::MAX as u128 - 1))
+		);
+
+		let (g, x, y) = extended_gcd(240, 46);
+		assert_eq!((g, 240 * x + 46 * y), (2, 2));
+		assert_eq!(extended_gcd(-4, 6).0, 2);
+		assert_eq!(mod_inverse(3, 11), Some(4));
+		assert_eq!(mod_inverse(-3, 11), Some(7));
+		assert_eq!(mod_inverse(4, 6), None);
+	}
+
+	#[test]
+	fn chinese_remainders() -> Result<()> {
+		let congruences = [(2, 3), (3, 5), (2, 7)].map(|(r, m)| Congruence::new(r, m));
+		assert_eq!(crt(congruences)?, Congruence::new(23, 105));
+
+		let congruences = [Congruence::new(2, 4), Congruence::new(4, 6)];
+		assert_eq!(crt(congruences)?, Congruence::new(10, 12));
+
+		let error = crt([Congruence::new(1, 4), Congruence::new(2, 6)]).expect_err("unsolvable");
+		assert_eq!(error.to_string(), "x ≡ 2 (mod 6) contradicts x ≡ 1 (mod 4)");
+		assert!(crt([Congruence::new(0, i128::MAX), Congruence::new(0, i128::MAX - 1)]).is_err());
+		assert_eq!(crt([])?, Congruence::new(0, 1));
+
+		assert_eq!(Congruence::new(0, 6).smallest_from(3), Some(6));
+		assert_eq!(Congruence::new(-1, 6).smallest_from(0), Some(5));
+		assert_eq!(Congruence::new(5, 6).smallest_from(12), Some(17));
+		Ok(())
+	}
+}
@@ -0,0 +1,205 @@
+use std::{fmt::Display, ops::Range, str::FromStr};
+
+/// Error of a parser, pointing at the text of the input it rejected.
+///
+/// The error is created from a slice of the input and located in it, as line
+/// and columns, by the runner once parsing failed. The runner then maps the
+/// location from the normalized to the raw input, see
+/// [`SourceMap`](crate::input::SourceMap).
+#[derive(Debug)]
+pub struct ParseError {
+	message: String,
+	text: String,
+	/// Addresses of the rejected slice, to find it in the input.
+	addresses: Range<usize>,
+	location: Option<Location>,
+}
+
+/// Position of the rejected text in the input.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Location {
+	/// Line number, starting at 1.
+	pub line: usize,
+	/// Columns of the text on its first line in characters, starting at 1.
+	pub columns: Range<usize>,
+	/// The whole line.
+	pub source: String,
+}
+
+impl ParseError {
+	/// Error about `text`, which must be a slice of the parsed input to be
+	/// located in it.
+	pub fn new(text: &str, message: impl Display) -> Self {
+		let start = text.as_ptr() as usize;
+		Self {
+			message: message.to_string(),
+			text: text.to_owned(),
+			addresses: start..start + text.len(),
+			location: None,
+		}
+	}
+
+	pub fn message(&self) -> &str {
+		&self.message
+	}
+
+	pub fn text(&self) -> &str {
+		&self.text
+	}
+
+	/// Position in the input, `None` until located.
+	pub fn location(&self) -> Option<&Location> {
+		self.location.as_ref()
+	}
+
+	/// Find the rejected text in the input, if it is a slice of it.
+	pub fn locate(&mut self, input: &str) {
+		let base = input.as_ptr() as usize;
+		if self.addresses.start < base || self.addresses.end > base + input.len() {
+			return;
+		}
+		let start = self.addresses.start - base;
+		let line_start = input[..start].rfind('\n').map_or(0, |i| i + 1);
+		let line_end = input[start..].find('\n').map_or(input.len(), |i| start + i);
+		let end = (self.addresses.end - base).min(line_end);
+
+		let column = input[line_start..start].chars().count() + 1;
+		let width = input[start..end].chars().count();
+		self.location = Some(Location {
+			line: input[..start].matches('\n').count() + 1,
+			columns: column..column + width,
+			source: input[line_start..line_end].to_owned(),
+		});
+	}
+
+	/// Change the location, if the error is located.
+	pub fn map_location(&mut self, map: impl FnOnce(Location) -> Location) {
+		self.location = self.location.take().map(map);
+	}
+
+	/// Line of the input with carets under the rejected text and the message,
+	/// `None` if the error is not located.
+	pub fn snippet(&self) -> Option<String> {
+		let location = self.location.as_ref()?;
+		let number = location.line.to_string();
+		let margin = " ".repeat(number.len());
+		let indent = location
+			.source
+			.chars()
+			.take(location.columns.start - 1)
+			.map(|c| if c == '\t' { '\t' } else { ' ' })
+			.collect::<String>();
+		let carets = "^".repeat(location.columns.len().max(1));
+		Some(format!(
+			"{margin} |\n{number} | {}\n{margin} | {indent}{carets} {}\n",
+			location.source, self.message
+		))
+	}
+}
+
+impl Display for ParseError {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		match &self.location {
+			Some(location) => write!(
+				f,
+				"{} at line {}, column {}",
+				self.message, location.line, location.columns.start
+			),
+			None => {
+				let text = self.text.lines().next().unwrap_or_default();
+				write!(f, "{}: `{text}`", self.message)
+			}
+		}
+	}
+}
+
+impl std::error::Error for ParseError {}
+
+/// Locate the [`ParseError`] in the error, if there is one.
+pub fn locate(mut err: anyhow::Error, input: &str) -> anyhow::Error {
+	if let Some(parse_error) = err.downcast_mut::<ParseError>() {
+		parse_error.locate(input);
+	}
+	err
+}
+
+/// Parse `text` with [`FromStr`], failing with a [`ParseError`] about it.
+pub fn value<T: FromStr>(text: &str) -> Result<T, ParseError>
+where
+	T::Err: Display,
+{
+	text.parse().map_err(|err| ParseError::new(text, err))
+}
+
+/// Lines of a rectangular grid, failing if it is empty or a line has a
+/// different length than the first.
+pub fn grid_lines(input: &str) -> Result<Vec<&str>, ParseError> {
+	let lines = input.lines().collect::<Vec<_>>();
+	let width = lines.first().map_or(0, |line| line.len());
+	if width == 0 {
+		return Err(ParseError::new(input, "Expected a grid"));
+	}
+	match lines.iter().find(|line| line.len() != width) {
+		Some(line) => Err(ParseError::new(line, format!("Expected a line of length {width}"))),
+		None => Ok(lines),
+	}
+}
+
+/// Characters of the text, each with its slice for errors about it.
+pub fn chars(text: &str) -> impl Iterator<Item = (char, &str)> {
+	text.char_indices().map(|(i, c)| (c, &text[i..i + c.len_utf8()]))
+}
+
+/// Convert each character of the text with [`TryFrom<char>`], failing with a
+/// [`ParseError`] about the character.
+pub fn each_char<T: TryFrom<char>>(text: &str) -> impl Iterator<Item = Result<T, ParseError>> + '_
+where
+	T::Error: Display,
+{
+	chars(text).map(|(c, text)| T::try_from(c).map_err(|err| ParseError::new(text, err)))
+}
+
+/// Turn a missing value into a [`ParseError`] about the given text.
+pub trait OrParseError<T> {
+	fn or_parse_error(self, text: &str, message: impl Display) -> Result<T, ParseError>;
+}
+
+impl<T> OrParseError<T> for Option<T> {
+	fn or_parse_error(self, text: &str, message: impl Display) -> Result<T, ParseError> {
+		self.ok_or_else(|| ParseError::new(text, message))
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn location() {
+		let input = "seeds: 1 2\n\n50 9x 2\n";
+		let number = input.split_whitespace().nth(4).expect("fifth word");
+		let mut error = value::<u64>(number).expect_err("not a number");
+		assert_eq!(error.to_string(), "invalid digit found in string: `9x`");
+
+		error.locate(input);
+		assert_eq!(
+			error.location(),
+			Some(&Location { line: 3, columns: 4..6, source: "50 9x 2".to_owned() })
+		);
+		assert_eq!(error.to_string(), "invalid digit found in string at line 3, column 4");
+		assert_eq!(
+			error.snippet().as_deref(),
+			Some("  |\n3 | 50 9x 2\n  |    ^^ invalid digit found in string\n")
+		);
+
+		let mut elsewhere = ParseError::new("9x", "elsewhere");
+		elsewhere.locate(input);
+		assert_eq!(elsewhere.location(), None);
+
+		let grid = "#.#\n..\n";
+		let mut error = grid_lines(grid).expect_err("ragged grid");
+		error.locate(grid);
+		assert_eq!(error.to_string(), "Expected a line of length 3 at line 2, column 1");
+		assert_eq!(chars("aé").map(|(_, text)| text).collect::<Vec<_>>(), ["a", "é"]);
+	}
+}
@@ -0,0 +1,602 @@
+use std::{fmt::Write, time::Duration};
+
+use anyhow::Result;
+use clap::ValueEnum;
+use serde_json::{json, Value};
+
+use crate::{
+	alloc::{AllocStats, Bytes},
+	answer::Answer,
+	crash::Crashed,
+	parse::ParseError,
+	runner::{Part, TimedOut},
+};
+
+/// Output format of the run reports.
+#[derive(Debug, Clone, Copy, PartialEq, Eq, Default, ValueEnum)]
+pub enum Format {
+	/// Aligned summary table.
+	#[default]
+	Text,
+	/// JSON document with all days and parts.
+	Json,
+	/// One CSV row per part or skipped day.
+	Csv,
+	/// Markdown progress table to paste into the README.
+	Markdown,
+}
+
+/// Result of running (or trying to run) a single day.
+#[derive(Debug)]
+pub struct DayReport {
+	pub year: u16,
+	pub day: u8,
+	/// Name of the input set, `None` for the default or an explicit input.
+	pub input: Option<String>,
+	pub outcome: Outcome,
+}
+
+#[derive(Debug)]
+pub enum Outcome {
+	/// The day was not run, with the reason why.
+	Skipped(String),
+	/// Parsing the input failed, so no part was run.
+	ParseFailed { error: anyhow::Error, duration: Duration },
+	/// The day was run, parts that were not selected are `None`.
+	Solved {
+		parse: Duration,
+		/// Allocations while parsing, with the `alloc-stats` feature.
+		parse_alloc: Option<AllocStats>,
+		part1: Option<PartReport>,
+		part2: Option<PartReport>,
+	},
+}
+
+/// Answer and timing of a single part.
+#[derive(Debug)]
+pub struct PartReport {
+	pub answer: Result<Answer>,
+	pub duration: Duration,
+	/// Allocations of the part, with the `alloc-stats` feature.
+	pub alloc: Option<AllocStats>,
+}
+
+impl PartReport {
+	/// Status name used in machine-readable output.
+	pub fn status(&self) -> &'static str {
+		match &self.answer {
+			Ok(_) => "ok",
+			Err(err) if err.is::<TimedOut>() => "timed_out",
+			Err(err) if err.is::<Crashed>() => "crashed",
+			Err(_) => "error",
+		}
+	}
+}
+
+/// Label of a day and its input set, like `6 (alice)`.
+pub fn label(day: u8, input: Option<&str>) -> String {
+	match input {
+		Some(input) => format!("{day} ({input})"),
+		None => day.to_string(),
+	}
+}
+
+impl DayReport {
+	/// Label of the day and its input set.
+	pub fn label(&self) -> String {
+		label(self.day, self.input.as_deref())
+	}
+
+	/// Iterate over the parts that were run.
+	pub fn parts(&self) -> impl Iterator<Item = (Part, &PartReport)> {
+		match &self.outcome {
+			Outcome::Skipped(_) | Outcome::ParseFailed { .. } => None,
+			Outcome::Solved { part1, part2, .. } => {
+				Some([(Part::One, part1.as_ref()), (Part::Two, part2.as_ref())])
+			}
+		}
+		.into_iter()
+		.flatten()
+		.filter_map(|(part, report)| Some((part, report?)))
+	}
+
+	/// Counted allocations of the parse step and the parts, labelled with the
+	/// step.
+	pub fn alloc_stats(&self) -> Vec<(String, AllocStats)> {
+		let Outcome::Solved { parse_alloc, .. } = &self.outcome else {
+			return Vec::new();
+		};
+		let parse = parse_alloc.map(|stats| ("parse".to_owned(), stats));
+		let parts =
+			self.parts().filter_map(|(part, report)| Some((part.to_string(), report.alloc?)));
+		parse.into_iter().chain(parts).collect()
+	}
+
+	/// Time spent parsing the input, if it was parsed.
+	pub fn parse_duration(&self) -> Option<Duration> {
+		match &self.outcome {
+			Outcome::Skipped(_) => None,
+			Outcome::ParseFailed { duration, .. } => Some(*duration),
+			Outcome::Solved { parse, .. } => Some(*parse),
+		}
+	}
+
+	/// Time spent parsing and solving.
+	pub fn total_duration(&self) -> Duration {
+		self.parse_duration().unwrap_or_default()
+			+ self.parts().map(|(_, part)| part.duration).sum::<Duration>()
+	}
+}
+
+/// Number of parts that returned an error, counting a failed parse as one.
+pub fn num_failed(reports: &[DayReport]) -> usize {
+	let failed_parses = reports
+		.iter()
+		.filter(|report| matches!(report.outcome, Outcome::ParseFailed { .. }))
+		.count();
+	let failed_parts =
+		reports.iter().flat_map(DayReport::parts).filter(|(_, part)| part.answer.is_err()).count();
+	failed_parses + failed_parts
+}
+
+/// Render the reports in the given format.
+pub fn render(reports: &[DayReport], format: Format) -> String {
+	match format {
+		Format::Text => summary_table(reports),
+		Format::Json => json(reports),
+		Format::Csv => csv(reports),
+		Format::Markdown => markdown_table(reports),
+	}
+}
+
+/// Render the reports as a summary table with a grand total.
+pub fn summary_table(reports: &[DayReport]) -> String {
+	let mut rows = vec![["Day", "Parse", "Part 1", "Time", "Part 2", "Time"].map(String::from)];
+	let mut total_parse = Duration::ZERO;
+	let mut total1 = Duration::ZERO;
+	let mut total2 = Duration::ZERO;
+	for report in reports {
+		let day = report.label();
+		match &report.outcome {
+			Outcome::Skipped(reason) => {
+				let mut row = ["-"; 6].map(String::from);
+				row[0] = day;
+				row[2] = format!("skipped ({reason})");
+				rows.push(row);
+			}
+			Outcome::ParseFailed { error, duration } => {
+				total_parse += *duration;
+				let mut row = ["-"; 6].map(String::from);
+				row[0] = day;
+				row[1] = format!("{duration:.2?}");
+				row[2] = format!("parse error: {error:#}");
+				rows.push(row);
+			}
+			Outcome::Solved { parse, part1, part2, .. } => {
+				total_parse += *parse;
+				let [answer1, time1] = part_cells(part1.as_ref(), &mut total1);
+				let [answer2, time2] = part_cells(part2.as_ref(), &mut total2);
+				rows.push([day, format!("{parse:.2?}"), answer1, time1, answer2, time2]);
+			}
+		}
+	}
+	rows.push([
+		"Total".into(),
+		format!("{total_parse:.2?}"),
+		String::new(),
+		format!("{total1:.2?}"),
+		String::new(),
+		format!("{total2:.2?}"),
+	]);
+
+	let mut table = render_table(&rows, true);
+	writeln!(table, "\nGrand total: {:.2?}", total_parse + total1 + total2)
+		.expect("writing to String");
+
+	if let Some(allocs) = alloc_table(reports) {
+		writeln!(table, "\n{allocs}").expect("writing to String");
+	}
+
+	// Multi-line answers do not fit into the table.
+	for report in reports {
+		for (part, part_report) in report.parts() {
+			if let Ok(answer @ Answer::MultiLine(_)) = &part_report.answer {
+				writeln!(table, "\nDay {} part {part}:\n{answer}", report.label())
+					.expect("writing to String");
+			}
+		}
+	}
+
+	// Parse errors are shown with the line of the input they point at.
+	for report in reports {
+		let parse_error = match &report.outcome {
+			Outcome::ParseFailed { error, .. } => Some(("parse".to_owned(), error)),
+			_ => None,
+		};
+		let part_errors = report.parts().filter_map(|(part, part_report)| {
+			part_report.answer.as_ref().err().map(|error| (format!("part {part}"), error))
+		});
+		for (step, error) in parse_error.into_iter().chain(part_errors) {
+			if let Some(snippet) = error.downcast_ref::<ParseError>().and_then(ParseError::snippet)
+			{
+				write!(table, "\nDay {} {step} error:\n{snippet}", report.label())
+					.expect("writing to String");
+			}
+		}
+	}
+	table
+}
+
+/// Table of the counted allocations per step, `None` if none were counted.
+fn alloc_table(reports: &[DayReport]) -> Option<String> {
+	let mut rows = vec![["Day", "Step", "Allocated", "Allocations", "Peak"].map(String::from)];
+	for report in reports {
+		for (step, stats) in report.alloc_stats() {
+			rows.push([
+				report.label(),
+				step,
+				Bytes(stats.bytes).to_string(),
+				stats.count.to_string(),
+				Bytes(stats.peak).to_string(),
+			]);
+		}
+	}
+	(rows.len() > 1).then(|| render_table(&rows, false))
+}
+
+/// Render the reports as JSON document, with durations in nanoseconds.
+pub fn json(reports: &[DayReport]) -> String {
+	let days = reports
+		.iter()
+		.map(|report| match &report.outcome {
+			Outcome::Skipped(reason) => {
+				json!({
+					"year": report.year,
+					"day": report.day,
+					"input": report.input,
+					"status": "skipped",
+					"reason": reason,
+				})
+			}
+			Outcome::ParseFailed { error, duration } => json!({
+				"year": report.year,
+				"day": report.day,
+				"input": report.input,
+				"status": "parse_failed",
+				"error": format!("{error:#}"),
+				"parse_duration_ns": nanos(*duration),
+			}),
+			Outcome::Solved { parse, parse_alloc, .. } => {
+				let parts = report
+					.parts()
+					.map(|(part, part_report)| {
+						let (answer, kind, error) = match &part_report.answer {
+							Ok(answer) => (answer_json(answer), Some(answer.kind()), None),
+							Err(err) => (Value::Null, None, Some(format!("{err:#}"))),
+						};
+						json!({
+							"part": part.number(),
+							"status": part_report.status(),
+							"answer": answer,
+							"answer_kind": kind,
+							"error": error,
+							"duration_ns": nanos(part_report.duration),
+							"alloc": alloc_json(part_report.alloc),
+						})
+					})
+					.collect::<Vec<_>>();
+				json!({
+					"year": report.year,
+					"day": report.day,
+					"input": report.input,
+					"status": "solved",
+					"parse_duration_ns": nanos(*parse),
+					"parse_alloc": alloc_json(*parse_alloc),
+					"parts": parts,
+				})
+			}
+		})
+		.collect::<Vec<_>>();
+	let total = reports.iter().map(DayReport::total_duration).sum();
+
+	let document = json!({ "days": days, "total_duration_ns": nanos(total) });
+	let mut json = serde_json::to_string_pretty(&document).expect("serializing JSON value");
+	json.push('\n');
+	json
+}
+
+fn alloc_json(stats: Option<AllocStats>) -> Value {
+	match stats {
+		Some(stats) => {
+			json!({ "bytes": stats.bytes, "count": stats.count, "peak_bytes": stats.peak })
+		}
+		None => Value::Null,
+	}
+}
+
+/// Integers that fit into 64 bits are JSON numbers, everything else is a
+/// string to not lose precision.
+pub fn answer_json(answer: &Answer) -> Value {
+	match answer {
+		Answer::Int(number) => (*number).into(),
+		answer => answer.to_string().into(),
+	}
+}
+
+/// Render the reports as CSV with one row per parse, part or skipped day,
+/// with durations in nanoseconds.
+pub fn csv(reports: &[DayReport]) -> String {
+	let mut csv = String::from(
+		"year,day,input,part,status,answer,duration_ns,error,alloc_bytes,alloc_count,peak_bytes\n",
+	);
+	for report in reports {
+		let input = csv_field(report.input.as_deref().unwrap_or_default());
+		match &report.outcome {
+			Outcome::Skipped(reason) => writeln!(
+				csv,
+				"{},{},{},,skipped,,,{}{}",
+				report.year,
+				report.day,
+				input,
+				csv_field(reason),
+				alloc_csv(None)
+			),
+			Outcome::ParseFailed { error, duration } => writeln!(
+				csv,
+				"{},{},{},parse,error,,{},{}{}",
+				report.year,
+				report.day,
+				input,
+				nanos(*duration),
+				csv_field(&format!("{error:#}")),
+				alloc_csv(None)
+			),
+			Outcome::Solved { parse, parse_alloc, .. } => writeln!(
+				csv,
+				"{},{},{},parse,ok,,{},{}",
+				report.year,
+				report.day,
+				input,
+				nanos(*parse),
+				alloc_csv(*parse_alloc)
+			),
+		}
+		.expect("writing to String");
+		for (part, part_report) in report.parts() {
+			let (answer, error) = match &part_report.answer {
+				Ok(answer) => (csv_field(&answer.to_string()), String::new()),
+				Err(err) => (String::new(), csv_field(&format!("{err:#}"))),
+			};
+			writeln!(
+				csv,
+				"{},{},{input},{},{},{answer},{},{error}{}",
+				report.year,
+				report.day,
+				part.number(),
+				part_report.status(),
+				nanos(part_report.duration),
+				alloc_csv(part_report.alloc)
+			)
+			.expect("writing to String");
+		}
+	}
+	csv
+}
+
+/// Allocation columns including the leading separator, empty if allocations
+/// were not counted.
+fn alloc_csv(stats: Option<AllocStats>) -> String {
+	match stats {
+		Some(stats) => format!(",{},{},{}", stats.bytes, stats.count, stats.peak),
+		None => ",,,".to_owned(),
+	}
+}
+
+/// Quote a CSV field if necessary.
+fn csv_field(field: &str) -> String {
+	if field.contains([',', '"', '\n', '\r']) {
+		format!("\"{}\"", field.replace('"', "\"\""))
+	} else {
+		field.to_owned()
+	}
+}
+
+/// Render the reports as Markdown progress table with a star per solved part.
+pub fn markdown_table(reports: &[DayReport]) -> String {
+	let mut table = String::from("| Day | Parse | Part 1 | Time | Part 2 | Time |\n");
+	table.push_str("| --: | ----: | :----: | ---: | :----: | ---: |\n");
+	let mut parse_total = Duration::ZERO;
+	let mut part_totals = [(0, Duration::ZERO); 2];
+	for report in reports {
+		let mut day =
+			format!("[{0}](https://adventofcode.com/{1}/day/{0})", report.day, report.year);
+		if let Some(input) = &report.input {
+			write!(day, " ({input})").expect("writing to String");
+		}
+		match &report.outcome {
+			Outcome::Skipped(reason) => {
+				writeln!(table, "| {day} | | skipped ({reason}) | | | |")
+					.expect("writing to String");
+			}
+			Outcome::ParseFailed { duration, .. } => {
+				parse_total += *duration;
+				writeln!(table, "| {day} | {duration:.2?} | ❌ | | ❌ | |")
+					.expect("writing to String");
+			}
+			Outcome::Solved { parse, part1, part2, .. } => {
+				parse_total += *parse;
+				let mut cells = vec![format!("{parse:.2?}")];
+				for (part, (stars, total)) in [part1, part2].into_iter().zip(&mut part_totals) {
+					match part {
+						None => cells.extend(["", ""].map(String::from)),
+						Some(part) => {
+							*total += part.duration;
+							let star = if part.answer.is_ok() {
+								*stars += 1;
+								"⭐"
+							} else {
+								"❌"
+							};
+							cells.extend([star.to_owned(), format!("{:.2?}", part.duration)]);
+						}
+					}
+				}
+				writeln!(table, "| {day} | {} |", cells.join(" | ")).expect("writing to String");
+			}
+		}
+	}
+	let [(stars1, total1), (stars2, total2)] = part_totals;
+	writeln!(
+		table,
+		"| **Total** | {parse_total:.2?} | {stars1} ⭐ | {total1:.2?} | {stars2} ⭐ | {total2:.2?} |"
+	)
+	.expect("writing to String");
+	table
+}
+
+pub fn nanos(duration: Duration) -> u64 {
+	duration.as_nanos().try_into().unwrap_or(u64::MAX)
+}
+
+/// Render rows as an aligned text table. The first row is the header, the
+/// last row is separated as footer if `footer` is set.
+pub fn render_table<const N: usize>(rows: &[[String; N]], footer: bool) -> String {
+	let mut widths = [0; N];
+	for row in rows {
+		for (width, cell) in widths.iter_mut().zip(row) {
+			*width = (*width).max(cell.chars().count());
+		}
+	}
+
+	let separator = widths.map(|width| "-".repeat(width + 2)).join("+");
+	let mut table = String::new();
+	for (i, row) in rows.iter().enumerate() {
+		if i == 1 || (footer && i == rows.len() - 1) {
+			writeln!(table, "{separator}").expect("writing to String");
+		}
+		let line = row
+			.iter()
+			.zip(widths)
+			.map(|(cell, width)| format!(" {cell:<width$} "))
+			.collect::<Vec<_>>()
+			.join("|");
+		writeln!(table, "{}", line.trim_end()).expect("writing to String");
+	}
+	table
+}
+
+/// Answer and time cells of a part, adding its duration to the total.
+fn part_cells(part: Option<&PartReport>, total: &mut Duration) -> [String; 2] {
+	let Some(part) = part else {
+		return ["-".into(), "-".into()];
+	};
+	*total += part.duration;
+	let answer = match &part.answer {
+		Ok(Answer::MultiLine(lines)) => format!("({} lines, see below)", lines.len()),
+		Ok(answer) => answer.to_string(),
+		Err(err) if err.is::<TimedOut>() || err.is::<Crashed>() => err.to_string(),
+		Err(err) => format!("error: {err:#}"),
+	};
+	[answer, format!("{:.2?}", part.duration)]
+}
+
+#[cfg(test)]
+mod tests {
+	use anyhow::anyhow;
+
+	use super::*;
+
+	#[test]
+	fn formats() {
+		let reports = vec![
+			DayReport {
+				year: 2023,
+				day: 1,
+				input: None,
+				outcome: Outcome::Solved {
+					parse: Duration::from_millis(1),
+					parse_alloc: Some(AllocStats { bytes: 2048, count: 3, peak: 1024 }),
+					part1: Some(PartReport {
+						answer: Ok(Answer::Int(142)),
+						duration: Duration::from_millis(2),
+						alloc: Some(AllocStats { bytes: 100, count: 1, peak: 100 }),
+					}),
+					part2: Some(PartReport {
+						answer: Err(anyhow!("boom")),
+						duration: Duration::from_millis(1),
+						alloc: None,
+					}),
+				},
+			},
+			DayReport {
+				year: 2023,
+				day: 2,
+				input: None,
+				outcome: Outcome::Skipped("no input file".to_owned()),
+			},
+			DayReport {
+				year: 2023,
+				day: 3,
+				input: Some("edge".to_owned()),
+				outcome: Outcome::ParseFailed {
+					error: anyhow!("bad line"),
+					duration: Duration::from_millis(1),
+				},
+			},
+		];
+
+		let table = summary_table(&reports);
+		assert!(table.contains(" 1        | 1.00ms | 142 "));
+		assert!(table.contains("error: boom"));
+		assert!(table.contains(" 2        | -      | skipped (no input file) "));
+		assert!(table.contains(" 3 (edge) | 1.00ms | parse error: bad line "));
+		assert!(table.contains("Grand total: 5.00ms"));
+		assert!(table.contains(" 1   | parse | 2.00 KiB  | 3           | 1.00 KiB"));
+		assert!(table.contains(" 1   | 1     | 100 B     | 1           | 100 B"));
+		assert_eq!(num_failed(&reports), 2);
+
+		let json = json(&reports);
+		assert!(json.contains(r#""answer": 142"#));
+		assert!(json.contains(r#""answer_kind": "int""#));
+		assert!(json.contains(r#""duration_ns": 2000000"#));
+		assert!(json.contains(r#""error": "boom""#));
+		assert!(json.contains(r#""reason": "no input file""#));
+		assert!(json.contains(r#""year": 2023"#));
+		assert!(json.contains(r#""input": "edge""#));
+		assert!(json.contains(r#""parse_duration_ns": 1000000"#));
+		assert!(json.contains(r#""status": "parse_failed""#));
+		assert!(json.contains(r#""total_duration_ns": 5000000"#));
+		assert!(json.contains(r#""peak_bytes": 1024"#));
+
+		let csv = csv(&reports);
+		assert_eq!(
+			csv,
+			"year,day,input,part,status,answer,duration_ns,error,alloc_bytes,alloc_count,peak_bytes\n\
+			 2023,1,,parse,ok,,1000000,,2048,3,1024\n2023,1,,1,ok,142,2000000,,100,1,100\n2023,1,,2,\
+			 error,,1000000,boom,,,\n2023,2,,,skipped,,,no input file,,,\n2023,3,edge,parse,error,,\
+			 1000000,bad line,,,\n"
+		);
+
+		let markdown = markdown_table(&reports);
+		assert!(markdown
+			.contains("| [1](https://adventofcode.com/2023/day/1) | 1.00ms | ⭐ | 2.00ms | ❌ |"));
+		assert!(markdown.contains("| **Total** | 2.00ms | 1 ⭐ | 2.00ms | 0 ⭐ | 1.00ms |"));
+	}
+
+	#[test]
+	fn parse_errors() {
+		let input = "1 2\n3 x\n";
+		let mut error = ParseError::new(&input[6..7], "Expected a number");
+		error.locate(input);
+		let reports = [DayReport {
+			year: 2023,
+			day: 9,
+			input: None,
+			outcome: Outcome::ParseFailed { error: error.into(), duration: Duration::ZERO },
+		}];
+
+		let table = summary_table(&reports);
+		assert!(table.contains("parse error: Expected a number at line 2, column 3"));
+		assert!(table.ends_with("\nDay 9 parse error:\n  |\n2 | 3 x\n  |   ^ Expected a number\n"));
+	}
+}
@@ -0,0 +1,488 @@
+use std::{
+	collections::BTreeSet,
+	fmt::Display,
+	io::{ErrorKind, Read},
+	path::{Path, PathBuf},
+	sync::{
+		atomic::Ordering,
+		mpsc::{self, RecvTimeoutError},
+		Arc, Mutex, PoisonError,
+	},
+	time::{Duration, Instant},
+};
+
+use anyhow::{anyhow, ensure, Context, Result};
+use clap::ValueEnum;
+use rayon::{prelude::*, ThreadPool};
+
+use crate::{
+	alloc,
+	context::{Progress, RunContext},
+	crash,
+	days::{self, ParsedInput, Puzzle},
+	report::{self, DayReport, Outcome, PartReport},
+	selection::{DaySelection, DEFAULT_YEAR},
+};
+
+/// Part of a day's puzzle.
+#[derive(Debug, PartialEq, Eq, PartialOrd, Ord, Clone, Copy, Hash, ValueEnum)]
+pub enum Part {
+	#[value(name = "1")]
+	One,
+	#[value(name = "2")]
+	Two,
+}
+
+impl Part {
+	pub fn number(self) -> u8 {
+		match self {
+			Self::One => 1,
+			Self::Two => 2,
+		}
+	}
+}
+
+impl std::fmt::Display for Part {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "{}", self.number())
+	}
+}
+
+/// Options controlling what is run for each day.
+#[derive(Debug, Default, Clone)]
+pub struct RunOptions {
+	/// Only run this part instead of both.
+	pub part: Option<Part>,
+	/// Read the input from this file instead of
+	/// `./inputs/{year}/day_{day}.txt`, `-` for stdin.
+	pub input: Option<PathBuf>,
+	/// Input sets to run each day on, unless there is an explicit input.
+	pub input_sets: InputSets,
+	/// Number of days to run concurrently, defaults to the number of CPUs.
+	/// Always 1 with the `alloc-stats` feature.
+	pub jobs: Option<usize>,
+	/// Give up on a part after it ran this long. The part is abandoned, not
+	/// stopped, see [`run_part`].
+	pub timeout: Option<Duration>,
+	/// Draw a live progress line on stderr for parts reporting progress.
+	pub progress: bool,
+}
+
+/// Which of a day's inputs to run on. Besides the default input
+/// `./inputs/{year}/day_{day}.txt`, a day can have named input sets
+/// `./inputs/{year}/day_{day}/<name>.txt`.
+#[derive(Debug, Default, Clone, PartialEq, Eq)]
+pub enum InputSets {
+	/// Only the default input.
+	#[default]
+	Default,
+	/// Only the input set with this name.
+	Named(String),
+	/// The default input and all input sets.
+	All,
+}
+
+/// Minimum time between redraws of the progress line.
+const PROGRESS_INTERVAL: Duration = Duration::from_millis(100);
+
+/// Error of a part that did not finish within the time limit.
+#[derive(Debug)]
+pub struct TimedOut(pub Duration);
+
+impl Display for TimedOut {
+	fn fmt(&self, f: &mut std::fmt::Formatter<'_>) -> std::fmt::Result {
+		write!(f, "timed out after {:.2?}", self.0)
+	}
+}
+
+impl std::error::Error for TimedOut {}
+
+impl RunOptions {
+	fn runs(&self, part: Part) -> bool {
+		self.part.is_none_or(|only| only == part)
+	}
+}
+
+/// Run all selected days of the year concurrently on the selected inputs and
+/// collect their reports, one per day and input. Days that are not
+/// implemented or have no input file are reported as skipped.
+pub fn run_days(
+	year: u16,
+	selection: &DaySelection,
+	options: &RunOptions,
+) -> Result<Vec<DayReport>> {
+	let days = selected_days(year, selection);
+	ensure!(
+		options.input.is_none() || days.len() == 1,
+		"An explicit input can only be used with a single day"
+	);
+
+	// Allocations are counted globally, so days must not overlap.
+	let jobs = if cfg!(feature = "alloc-stats") { 1 } else { options.jobs.unwrap_or_default() };
+	let pool = || {
+		rayon::ThreadPoolBuilder::new()
+			.num_threads(jobs)
+			.build()
+			.context("Could not create the thread pool")
+	};
+	// The days wait for their steps, which run in a pool of their own so a
+	// waiting day does not keep a step from running.
+	let (days_pool, steps_pool) = (pool()?, pool()?);
+	let progress = options.progress.then(|| Arc::new(ProgressLine::default()));
+	let reports = days_pool.install(|| {
+		days.into_par_iter()
+			.map(|day| {
+				let skipped = |reason: &str| {
+					let outcome = Outcome::Skipped(reason.to_owned());
+					Ok(vec![DayReport { year, day, input: None, outcome }])
+				};
+				let Some(solution) = days::DAYS.get(&(year, day)) else {
+					return skipped("not implemented");
+				};
+				let inputs = fetch_inputs(year, day, options)?;
+				if inputs.is_empty() {
+					return match &options.input_sets {
+						InputSets::Named(name) => skipped(&format!("no input set `{name}`")),
+						_ => skipped("no input file"),
+					};
+				}
+				let reports = inputs
+					.into_iter()
+					.map(|(name, input)| {
+						let label = report::label(day, name.as_deref());
+						let run = PartRun {
+							label,
+							options,
+							pool: &steps_pool,
+							progress: progress.as_ref(),
+						};
+						let outcome = run_day(solution.as_ref(), &input, &run);
+						DayReport { year, day, input: name, outcome }
+					})
+					.collect();
+				Ok(reports)
+			})
+			.collect::<Result<Vec<Vec<_>>>>()
+	});
+	if let Some(progress) = progress {
+		progress.close();
+	}
+	Ok(reports?.into_iter().flatten().collect())
+}
+
+/// The days of the year to consider for the selection, which includes
+/// explicitly selected days that are not implemented.
+pub fn selected_days(year: u16, selection: &DaySelection) -> BTreeSet<u8> {
+	match selection.explicit() {
+		Some(days) => days.clone(),
+		None => days::DAYS.range((year, 0)..=(year, u8::MAX)).map(|((_, day), _)| *day).collect(),
+	}
+}
+
+/// What the parts of a day need to know to run.
+struct PartRun<'a> {
+	/// Label of the day and input in the progress line.
+	label: String,
+	options: &'a RunOptions,
+	/// Pool running the parse step and the parts, sized by the `jobs` option.
+	pool: &'a ThreadPool,
+	progress: Option<&'a Arc<ProgressLine>>,
+}
+
+impl PartRun<'_> {
+	/// Context of the part, drawing its progress if enabled.
+	fn context(&self, part: Part) -> RunContext {
+		match self.progress {
+			Some(line) => {
+				let line = Arc::clone(line);
+				let label = format!("Day {} part {part}", self.label);
+				RunContext::with_progress(move |progress| line.draw(&label, progress))
+			}
+			None => RunContext::default(),
+		}
+	}
+}
+
+fn run_day(solution: &'static dyn Puzzle, input: &str, run: &PartRun) -> Outcome {
+	let time = Instant::now();
+	let (parsed, parse_alloc) =
+		run.pool.install(|| alloc::measure(|| crash::catch(|| solution.parse(input))));
+	let parse = time.elapsed();
+	let parsed = match parsed {
+		Ok(parsed) => parsed,
+		Err(error) => return Outcome::ParseFailed { error, duration: parse },
+	};
+
+	let parsed = Arc::new(parsed);
+	let [part1, part2] = [Part::One, Part::Two]
+		.map(|part| run.options.runs(part).then(|| run_part(solution, part, &parsed, run)));
+	Outcome::Solved { parse, parse_alloc, part1, part2 }
+}
+
+/// Run a part in the pool. With a time limit the part runs in a pool of its
+/// own of the same size instead, and the runner stops waiting for it once the
+/// limit is up after it started. It is reported as timed out and abandoned,
+/// not stopped: it is asked to cancel through its context, but keeps running
+/// on its own threads until it notices or finishes. Its allocations from then
+/// on are not counted.
+fn run_part(
+	solution: &'static dyn Puzzle,
+	part: Part,
+	parsed: &Arc<ParsedInput>,
+	run: &PartRun,
+) -> PartReport {
+	let ctx = run.context(part);
+	let Some(timeout) = run.options.timeout else {
+		return run.pool.install(|| solve_part(solution, part, parsed, &ctx));
+	};
+
+	let failed = |error: anyhow::Error| PartReport {
+		answer: Err(error),
+		duration: Duration::ZERO,
+		alloc: None,
+	};
+	let pool =
+		match rayon::ThreadPoolBuilder::new().num_threads(run.pool.current_num_threads()).build() {
+			Ok(pool) => pool,
+			Err(err) => return failed(anyhow!(err).context("Could not create the thread pool")),
+		};
+
+	let (started_sender, started) = mpsc::channel();
+	let (sender, receiver) = mpsc::channel();
+	let parsed = Arc::clone(parsed);
+	let cancel = ctx.cancel_flag();
+	let abandoned = Arc::clone(&cancel);
+	pool.spawn(move || {
+		let _ = started_sender.send(());
+		let report =
+			alloc::stop_counting_when(&abandoned, || solve_part(solution, part, &parsed, &ctx));
+		// The runner is gone if the part timed out.
+		let _ = sender.send(report);
+	});
+	if started.recv().is_err() {
+		return failed(anyhow!("The part did not start"));
+	}
+	match receiver.recv_timeout(timeout) {
+		Ok(report) => report,
+		Err(RecvTimeoutError::Timeout) => {
+			cancel.store(true, Ordering::Relaxed);
+			PartReport { answer: Err(TimedOut(timeout).into()), duration: timeout, alloc: None }
+		}
+		Err(RecvTimeoutError::Disconnected) => failed(anyhow!("The part panicked")),
+	}
+}
+
+/// Solve the part on this thread, measuring its time and allocations. A
+/// panic of the part is reported as its error.
+fn solve_part(
+	solution: &dyn Puzzle,
+	part: Part,
+	parsed: &ParsedInput,
+	ctx: &RunContext,
+) -> PartReport {
+	let time = Instant::now();
+	let (answer, alloc) = alloc::measure(|| crash::catch(|| solution.solve(part, parsed, ctx)));
+	PartReport { answer, duration: time.elapsed(), alloc }
+}
+
+/// Live progress line on stderr, shared by all running parts.
+#[derive(Debug, Default)]
+struct ProgressLine {
+	state: Mutex<LineState>,
+}
+
+#[derive(Debug, Default)]
+struct LineState {
+	last_draw: Option<Instant>,
+	closed: bool,
+}
+
+impl ProgressLine {
+	/// Redraw the line, unless it was redrawn recently.
+	fn draw(&self, label: &str, progress: Progress) {
+		let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+		if state.closed || state.last_draw.is_some_and(|last| last.elapsed() < PROGRESS_INTERVAL) {
+			return;
+		}
+		state.last_draw = Some(Instant::now());
+		eprint!("\r\x1b[2K{label}: {progress}");
+	}
+
+	/// Clear the line and stop drawing it.
+	fn close(&self) {
+		let mut state = self.state.lock().unwrap_or_else(PoisonError::into_inner);
+		if state.last_draw.is_some() {
+			eprint!("\r\x1b[2K");
+		}
+		state.closed = true;
+	}
+}
+
+/// Default location of the input of the given day. Inputs of the default
+/// year used to be kept in `./inputs/day_{day}.txt`, which is still used if
+/// it was not moved yet.
+pub fn input_path(year: u16, day: u8) -> String {
+	let path = format!("./inputs/{year}/day_{day}.txt");
+	let old_path = format!("./inputs/day_{day}.txt");
+	if year == DEFAULT_YEAR && !Path::new(&path).exists() && Path::new(&old_path).exists() {
+		return old_path;
+	}
+	path
+}
+
+/// Location of the named input set of the given day.
+pub fn input_set_path(year: u16, day: u8, name: &str) -> PathBuf {
+	PathBuf::from(format!("./inputs/{year}/day_{day}/{name}.txt"))
+}
+
+/// Read the selected inputs of the given day, with the name of their input
+/// set. Inputs that do not exist are left out.
+fn fetch_inputs(year: u16, day: u8, options: &RunOptions) -> Result<Vec<(Option<String>, String)>> {
+	if options.input.is_some() {
+		let input = fetch_input(year, day, options.input.as_deref())?;
+		return Ok(input.map(|input| (None, input)).into_iter().collect());
+	}
+
+	let mut inputs = Vec::new();
+	if matches!(options.input_sets, InputSets::Default | InputSets::All) {
+		if let Some(input) = fetch_input(year, day, None)? {
+			inputs.push((None, input));
+		}
+	}
+	let names = match &options.input_sets {
+		InputSets::Default => Vec::new(),
+		InputSets::Named(name) => vec![name.clone()],
+		InputSets::All => input_set_names(year, day)?,
+	};
+	for name in names {
+		if let Some(input) = read_optional(&input_set_path(year, day, &name))? {
+			inputs.push((Some(name), input));
+		}
+	}
+	Ok(inputs)
+}
+
+/// Names of all input sets of the given day, sorted.
+fn input_set_names(year: u16, day: u8) -> Result<Vec<String>> {
+	let dir = PathBuf::from(format!("./inputs/{year}/day_{day}"));
+	let entries = match std::fs::read_dir(&dir) {
+		Ok(entries) => entries,
+		Err(err) if err.kind() == ErrorKind::NotFound => return Ok(Vec::new()),
+		Err(err) => {
+			return Err(err).context(format!("Could not read directory `{}`", dir.display()))
+		}
+	};
+	let mut names = Vec::new();
+	for entry in entries {
+		let path = entry.context(format!("Could not read directory `{}`", dir.display()))?.path();
+		if path.extension().is_some_and(|extension| extension == "txt") {
+			if let Some(name) = path.file_stem().and_then(|stem| stem.to_str()) {
+				names.push(name.to_owned());
+			}
+		}
+	}
+	names.sort();
+	Ok(names)
+}
+
+/// Read the file, returning `None` if it does not exist.
+fn read_optional(path: &Path) -> Result<Option<String>> {
+	match std::fs::read_to_string(path) {
+		Ok(input) => Ok(Some(input)),
+		Err(err) if err.kind() == ErrorKind::NotFound => Ok(None),
+		Err(err) => Err(err).context(format!("Could not read file `{}`", path.display())),
+	}
+}
+
+/// Read the input of the given day, returning `None` if there is no default
+/// input file. An explicitly given input file must exist, `-` reads from
+/// stdin.
+pub fn fetch_input(year: u16, day: u8, explicit: Option<&Path>) -> Result<Option<String>> {
+	match explicit {
+		Some(path) if path == Path::new("-") => {
+			let mut input = String::new();
+			std::io::stdin().read_to_string(&mut input).context("Could not read stdin")?;
+			Ok(Some(input))
+		}
+		Some(path) => {
+			let input = std::fs::read_to_string(path)
+				.context(format!("Could not read file `{}`", path.display()))?;
+			Ok(Some(input))
+		}
+		None => read_optional(Path::new(&input_path(year, day))),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+	use crate::days::{Answer, AocDay};
+
+	struct Sleepy;
+
+	impl AocDay for Sleepy {
+		fn part1(&self, _input: &str) -> Result<Answer> {
+			Ok(Answer::Int(1))
+		}
+
+		fn part2(&self, _input: &str) -> Result<Answer> {
+			std::thread::sleep(Duration::from_secs(5));
+			Ok(Answer::Int(2))
+		}
+	}
+
+	fn pool() -> ThreadPool {
+		rayon::ThreadPoolBuilder::new().num_threads(1).build().expect("creating the pool")
+	}
+
+	#[test]
+	fn timeout() {
+		let options = RunOptions { timeout: Some(Duration::from_millis(50)), ..Default::default() };
+		let pool = pool();
+		let run = PartRun { label: "1".to_owned(), options: &options, pool: &pool, progress: None };
+		let Outcome::Solved { part1: Some(part1), part2: Some(part2), .. } =
+			run_day(&Sleepy, "", &run)
+		else {
+			panic!("both parts should have run");
+		};
+		assert_eq!(part1.answer.expect("part 1 finishes"), Answer::Int(1));
+		assert_eq!(part2.status(), "timed_out");
+		assert_eq!(part2.duration, Duration::from_millis(50));
+
+		// The abandoned part 2 does not keep the next day from running.
+		let Outcome::Solved { part1: Some(part1), .. } = run_day(&Sleepy, "", &run) else {
+			panic!("part 1 should have run");
+		};
+		assert_eq!(part1.answer.expect("part 1 finishes"), Answer::Int(1));
+	}
+
+	struct Crashy;
+
+	impl AocDay for Crashy {
+		fn part1(&self, input: &str) -> Result<Answer> {
+			let numbers = input.lines().collect::<Vec<_>>();
+			Ok(numbers[3].len().into())
+		}
+
+		fn part2(&self, _input: &str) -> Result<Answer> {
+			Ok(Answer::Int(2))
+		}
+	}
+
+	#[test]
+	fn crash() {
+		let options = RunOptions::default();
+		let pool = pool();
+		let run = PartRun { label: "1".to_owned(), options: &options, pool: &pool, progress: None };
+		let Outcome::Solved { part1: Some(part1), part2: Some(part2), .. } =
+			run_day(&Crashy, "1\n2\n", &run)
+		else {
+			panic!("both parts should have run");
+		};
+		assert_eq!(part1.status(), "crashed");
+		let error = part1.answer.expect_err("part 1 crashes").to_string();
+		assert!(error.starts_with("crashed at src/runner.rs:"), "{error}");
+		assert!(error.ends_with("index out of bounds: the len is 2 but the index is 3"));
+		assert_eq!(part2.answer.expect("part 2 finishes"), Answer::Int(2));
+	}
+}
@@ -0,0 +1,142 @@
+use std::{fs::OpenOptions, io::ErrorKind, path::Path};
+
+use anyhow::{bail, ensure, Context, Result};
+
+use crate::runner;
+
+/// Template of a new day's module.
+const TEMPLATE: &str = include_str!("days/new_day.rs");
+/// Directory of the year modules, relative to the repository root.
+const DAYS_DIR: &str = "./src/days";
+/// Invocation registering the implemented years in `days/mod.rs`.
+const YEAR_REGISTRATION: &str = "implemented_years!(";
+/// Invocation registering the implemented days in a year's `mod.rs`.
+const DAY_REGISTRATION: &str = "implemented_days!(";
+/// Module of a year without any days yet.
+const EMPTY_YEAR: &str = "use super::{Answer, AocDay};\n\nimplemented_days!();\n";
+
+/// Create the module of a new day from the template, register it in the
+/// year's `mod.rs` and create an empty input file. A missing year module is
+/// created and registered in `days/mod.rs`. Refuses to touch an existing day.
+pub fn new_day(year: u16, day: u8) -> Result<()> {
+	let days_dir = Path::new(DAYS_DIR);
+	let registry_file = days_dir.join("mod.rs");
+	let year_dir = days_dir.join(format!("year_{year}"));
+	let year_file = year_dir.join("mod.rs");
+	let module = year_dir.join(format!("day_{day}.rs"));
+	ensure!(
+		registry_file.exists(),
+		"Could not find `{}`, run this from the repository root",
+		registry_file.display()
+	);
+	ensure!(!module.exists(), "Day {day} of {year} already exists at `{}`", module.display());
+
+	if !year_file.exists() {
+		let registry = read(&registry_file)?;
+		let registry = register(&registry, YEAR_REGISTRATION, year)?;
+		std::fs::create_dir_all(&year_dir)
+			.context(format!("Could not create directory `{}`", year_dir.display()))?;
+		write(&year_file, EMPTY_YEAR)?;
+		write(&registry_file, &registry)?;
+		println!(
+			"Created `{}` and registered it in `{}`",
+			year_file.display(),
+			registry_file.display()
+		);
+	}
+
+	let days = read(&year_file)?;
+	let days = register(&days, DAY_REGISTRATION, day.into())?;
+	write(&module, TEMPLATE)?;
+	write(&year_file, &days)?;
+	println!("Created `{}` and registered it in `{}`", module.display(), year_file.display());
+
+	let input = runner::input_path(year, day);
+	if let Some(dir) = Path::new(&input).parent() {
+		std::fs::create_dir_all(dir)
+			.context(format!("Could not create directory `{}`", dir.display()))?;
+	}
+	match OpenOptions::new().write(true).create_new(true).open(&input) {
+		Ok(_) => println!("Created empty input file `{input}`"),
+		Err(err) if err.kind() == ErrorKind::AlreadyExists => {
+			println!("Keeping existing input file `{input}`");
+		}
+		Err(err) => return Err(err).context(format!("Could not create file `{input}`")),
+	}
+
+	Ok(())
+}
+
+fn read(path: &Path) -> Result<String> {
+	std::fs::read_to_string(path).context(format!("Could not read file `{}`", path.display()))
+}
+
+fn write(path: &Path, content: &str) -> Result<()> {
+	std::fs::write(path, content).context(format!("Could not write file `{}`", path.display()))
+}
+
+/// Insert the number into the sorted list of the registration macro
+/// invocation (`implemented_years!(` or `implemented_days!(`) in the module
+/// source.
+fn register(source: &str, invocation: &str, number: u16) -> Result<String> {
+	let start = source
+		.match_indices(invocation)
+		.map(|(start, _)| start)
+		.find(|start| source[..*start].ends_with('\n') || *start == 0)
+		.context(format!("Could not find the `{invocation}` invocation"))?;
+	let list_start = start + invocation.len();
+	let list_end = list_start
+		+ source[list_start..]
+			.find(");")
+			.context(format!("Unterminated `{invocation}` invocation"))?;
+
+	let mut numbers = source[list_start..list_end]
+		.split(',')
+		.map(str::trim)
+		.filter(|number| !number.is_empty())
+		.map(|number| {
+			number.parse::<u16>().context(format!("Invalid number `{number}` in `{invocation}`"))
+		})
+		.collect::<Result<Vec<_>>>()?;
+	if numbers.contains(&number) {
+		bail!("{number} is already registered in `{invocation}`");
+	}
+	numbers.push(number);
+	numbers.sort();
+
+	let list = numbers.iter().map(u16::to_string).collect::<Vec<_>>().join(", ");
+	Ok(format!("{}{list}{}", &source[..list_start], &source[list_end..]))
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn registration() -> Result<()> {
+		let registry = "macro_rules! implemented_days {}\n\nimplemented_days!(1, 2, 10);\n";
+		assert_eq!(
+			register(registry, DAY_REGISTRATION, 5)?,
+			"macro_rules! implemented_days {}\n\nimplemented_days!(1, 2, 5, 10);\n"
+		);
+		assert_eq!(
+			register("implemented_days!(\n\t1,\n\t2,\n);\n", DAY_REGISTRATION, 25)?,
+			"implemented_days!(1, 2, 25);\n"
+		);
+		assert_eq!(register(EMPTY_YEAR, DAY_REGISTRATION, 3)?, EMPTY_YEAR.replace("!()", "!(3)"));
+		assert_eq!(
+			register("implemented_years!(2023);\n", YEAR_REGISTRATION, 2022)?,
+			"implemented_years!(2022, 2023);\n"
+		);
+		assert!(register(registry, DAY_REGISTRATION, 10).is_err());
+		assert!(register("mod day_1;\n", DAY_REGISTRATION, 5).is_err());
+
+		Ok(())
+	}
+
+	#[test]
+	fn template() {
+		assert!(TEMPLATE.contains("impl AocDay for Day"));
+		assert!(TEMPLATE.contains("const INPUT: &str = r#\"\n\t\t\"#;"));
+	}
+}
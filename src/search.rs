@@ -0,0 +1,362 @@
+use std::{
+	cmp::{Ordering, Reverse},
+	collections::{BinaryHeap, VecDeque},
+	hash::Hash,
+	ops::Add,
+};
+
+use ahash::{AHashMap, AHashSet};
+
+/// Cheapest path found by a search.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Path<S, C> {
+	pub cost: C,
+	/// States from a start to the goal, both included.
+	pub states: Vec<S>,
+}
+
+/// All cheapest paths found by [`dijkstra_all`].
+#[derive(Debug)]
+pub struct AllPaths<S, C> {
+	pub cost: C,
+	explored: Explored<S, C>,
+	goals: Vec<usize>,
+}
+
+impl<S: Clone + Eq + Hash, C> AllPaths<S, C> {
+	/// Every cheapest path, from a start to a goal. There can be exponentially
+	/// many of them.
+	pub fn paths(&self) -> Vec<Vec<S>> {
+		let mut paths = Vec::new();
+		let mut stack = self.goals.iter().map(|&goal| vec![goal]).collect::<Vec<_>>();
+		while let Some(path) = stack.pop() {
+			let parents = &self.explored.parents[*path.last().expect("path is not empty")];
+			if parents.is_empty() {
+				paths.push(
+					path.iter().rev().map(|&index| self.explored.states[index].clone()).collect(),
+				);
+			}
+			for &parent in parents {
+				let mut path = path.clone();
+				path.push(parent);
+				stack.push(path);
+			}
+		}
+		paths
+	}
+
+	/// States on any cheapest path.
+	pub fn states(&self) -> AHashSet<S> {
+		let mut seen = AHashSet::new();
+		let mut stack = self.goals.clone();
+		while let Some(index) = stack.pop() {
+			if seen.insert(index) {
+				stack.extend(&self.explored.parents[index]);
+			}
+		}
+		seen.into_iter().map(|index| self.explored.states[index].clone()).collect()
+	}
+}
+
+/// States reached by a search with their cheapest costs and the states they
+/// were reached from at that cost.
+#[derive(Debug)]
+struct Explored<S, C> {
+	states: Vec<S>,
+	indices: AHashMap<S, usize>,
+	costs: Vec<C>,
+	parents: Vec<Vec<usize>>,
+	/// Whether to keep every parent reaching a state at its cheapest cost, or
+	/// only the first.
+	all_parents: bool,
+}
+
+impl<S: Clone + Eq + Hash, C: Copy + Ord> Explored<S, C> {
+	fn new(all_parents: bool) -> Self {
+		Self {
+			states: Vec::new(),
+			indices: AHashMap::new(),
+			costs: Vec::new(),
+			parents: Vec::new(),
+			all_parents,
+		}
+	}
+
+	/// Reach the state at the cost, returning its index if it has to be
+	/// expanded because it is new or cheaper than before.
+	fn visit(&mut self, state: S, cost: C, parent: Option<usize>) -> Option<usize> {
+		let Some(&index) = self.indices.get(&state) else {
+			let index = self.states.len();
+			self.indices.insert(state.clone(), index);
+			self.states.push(state);
+			self.costs.push(cost);
+			self.parents.push(parent.into_iter().collect());
+			return Some(index);
+		};
+		match cost.cmp(&self.costs[index]) {
+			Ordering::Less => {
+				self.costs[index] = cost;
+				self.parents[index] = parent.into_iter().collect();
+				Some(index)
+			}
+			Ordering::Equal if self.all_parents => {
+				if let Some(parent) = parent.filter(|&parent| self.is_new_parent(index, parent)) {
+					self.parents[index].push(parent);
+				}
+				None
+			}
+			Ordering::Equal | Ordering::Greater => None,
+		}
+	}
+
+	/// Whether the parent can be added to the state reached again at the same
+	/// cost. Starts never get a parent, and a parent must not close a cycle of
+	/// steps that cost nothing.
+	fn is_new_parent(&self, index: usize, parent: usize) -> bool {
+		let parents = &self.parents[index];
+		!parents.is_empty()
+			&& !parents.contains(&parent)
+			&& (self.costs[parent] != self.costs[index] || !self.is_ancestor(index, parent))
+	}
+
+	/// Whether `ancestor` is reached following the parents of the state.
+	fn is_ancestor(&self, ancestor: usize, index: usize) -> bool {
+		let mut seen = AHashSet::new();
+		let mut stack = vec![index];
+		while let Some(index) = stack.pop() {
+			if index == ancestor {
+				return true;
+			}
+			if seen.insert(index) {
+				stack.extend(&self.parents[index]);
+			}
+		}
+		false
+	}
+
+	/// Path to the state following the first parents.
+	fn path(&self, mut index: usize) -> Vec<S> {
+		let mut path = vec![self.states[index].clone()];
+		while let Some(&parent) = self.parents[index].first() {
+			path.push(self.states[parent].clone());
+			index = parent;
+		}
+		path.reverse();
+		path
+	}
+}
+
+/// Cheapest path from any of the starts to a goal, with the neighbours of a
+/// state and the non-negative costs to reach them.
+pub fn dijkstra<S, C, I>(
+	starts: impl IntoIterator<Item = S>,
+	neighbours: impl FnMut(&S) -> I,
+	is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+	S: Clone + Eq + Hash,
+	C: Copy + Ord + Default + Add<Output = C>,
+	I: IntoIterator<Item = (S, C)>,
+{
+	astar(starts, neighbours, |_| C::default(), is_goal)
+}
+
+/// Cheapest path like [`dijkstra`], exploring the states with the lowest cost
+/// plus heuristic first. The heuristic must never overestimate the remaining
+/// cost to a goal.
+pub fn astar<S, C, I>(
+	starts: impl IntoIterator<Item = S>,
+	neighbours: impl FnMut(&S) -> I,
+	heuristic: impl FnMut(&S) -> C,
+	is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, C>>
+where
+	S: Clone + Eq + Hash,
+	C: Copy + Ord + Default + Add<Output = C>,
+	I: IntoIterator<Item = (S, C)>,
+{
+	let (cost, goals, explored) = explore(starts, neighbours, heuristic, is_goal, false)?;
+	Some(Path { cost, states: explored.path(goals[0]) })
+}
+
+/// All cheapest paths from any of the starts to any goal, like [`dijkstra`].
+pub fn dijkstra_all<S, C, I>(
+	starts: impl IntoIterator<Item = S>,
+	neighbours: impl FnMut(&S) -> I,
+	is_goal: impl FnMut(&S) -> bool,
+) -> Option<AllPaths<S, C>>
+where
+	S: Clone + Eq + Hash,
+	C: Copy + Ord + Default + Add<Output = C>,
+	I: IntoIterator<Item = (S, C)>,
+{
+	let (cost, goals, explored) = explore(starts, neighbours, |_| C::default(), is_goal, true)?;
+	Some(AllPaths { cost, explored, goals })
+}
+
+/// Best-first search returning the cost of the cheapest goals, the goals and
+/// the explored states. Only the first goal and the first parent of each
+/// state are kept unless `all` is set.
+fn explore<S, C, I>(
+	starts: impl IntoIterator<Item = S>,
+	mut neighbours: impl FnMut(&S) -> I,
+	mut heuristic: impl FnMut(&S) -> C,
+	mut is_goal: impl FnMut(&S) -> bool,
+	all: bool,
+) -> Option<(C, Vec<usize>, Explored<S, C>)>
+where
+	S: Clone + Eq + Hash,
+	C: Copy + Ord + Default + Add<Output = C>,
+	I: IntoIterator<Item = (S, C)>,
+{
+	let mut explored = Explored::new(all);
+	let mut queue = BinaryHeap::new();
+	for start in starts {
+		if let Some(index) = explored.visit(start, C::default(), None) {
+			let priority = heuristic(&explored.states[index]);
+			queue.push(Reverse((priority, C::default(), index)));
+		}
+	}
+
+	let mut best = None;
+	let mut goals = Vec::new();
+	while let Some(Reverse((_, cost, index))) = queue.pop() {
+		if cost > explored.costs[index] {
+			continue;
+		}
+		if best.is_some_and(|best| cost > best) {
+			break;
+		}
+		let state = explored.states[index].clone();
+		if is_goal(&state) {
+			best = Some(cost);
+			goals.push(index);
+			if all {
+				continue;
+			}
+			break;
+		}
+		for (next, step_cost) in neighbours(&state) {
+			let next_cost = cost + step_cost;
+			if let Some(next_index) = explored.visit(next, next_cost, Some(index)) {
+				let priority = next_cost + heuristic(&explored.states[next_index]);
+				queue.push(Reverse((priority, next_cost, next_index)));
+			}
+		}
+	}
+	Some((best?, goals, explored))
+}
+
+/// Shortest path from any of the starts to a goal when every step costs 1.
+pub fn bfs<S, I>(
+	starts: impl IntoIterator<Item = S>,
+	mut neighbours: impl FnMut(&S) -> I,
+	is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+	S: Clone + Eq + Hash,
+	I: IntoIterator<Item = S>,
+{
+	zero_one_bfs(starts, |state| neighbours(state).into_iter().map(|next| (next, 1)), is_goal)
+}
+
+/// Cheapest path like [`dijkstra`] when every step costs 0 or 1.
+pub fn zero_one_bfs<S, I>(
+	starts: impl IntoIterator<Item = S>,
+	mut neighbours: impl FnMut(&S) -> I,
+	mut is_goal: impl FnMut(&S) -> bool,
+) -> Option<Path<S, usize>>
+where
+	S: Clone + Eq + Hash,
+	I: IntoIterator<Item = (S, usize)>,
+{
+	let mut explored = Explored::new(false);
+	let mut queue = VecDeque::new();
+	for start in starts {
+		queue.extend(explored.visit(start, 0, None).map(|index| (0, index)));
+	}
+
+	while let Some((cost, index)) = queue.pop_front() {
+		if cost > explored.costs[index] {
+			continue;
+		}
+		let state = explored.states[index].clone();
+		if is_goal(&state) {
+			return Some(Path { cost, states: explored.path(index) });
+		}
+		for (next, step_cost) in neighbours(&state) {
+			debug_assert!(step_cost <= 1, "0-1 BFS with a step cost of {step_cost}");
+			let next_cost = cost + step_cost;
+			if let Some(next_index) = explored.visit(next, next_cost, Some(index)) {
+				if step_cost == 0 {
+					queue.push_front((next_cost, next_index));
+				} else {
+					queue.push_back((next_cost, next_index));
+				}
+			}
+		}
+	}
+	None
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	/// Weighted edges of a small graph with two cheapest paths from 0 to 4.
+	fn edges(node: &u8) -> Vec<(u8, u32)> {
+		match node {
+			0 => vec![(1, 1), (2, 1), (3, 5)],
+			1 | 2 => vec![(4, 2)],
+			3 => vec![(4, 0)],
+			_ => vec![],
+		}
+	}
+
+	#[test]
+	fn paths() {
+		let path = dijkstra([0], edges, |&node| node == 4).expect("path");
+		assert_eq!(path.cost, 3);
+		assert!(path.states == [0, 1, 4] || path.states == [0, 2, 4], "{:?}", path.states);
+		assert_eq!(dijkstra([0], edges, |&node| node == 9), None);
+
+		let all = dijkstra_all([0], edges, |&node| node == 4).expect("paths");
+		let mut paths = all.paths();
+		paths.sort();
+		assert_eq!(paths, [[0, 1, 4], [0, 2, 4]]);
+		assert_eq!(all.states().len(), 4);
+
+		// Walk on a line towards 10, the heuristic is the distance left.
+		let line = |&x: &i32| [(x - 1, 1), (x + 1, 1)];
+		let path = astar([0], line, |&x| 10 - x.min(10), |&x| x == 10).expect("path");
+		assert_eq!(path.states, (0..=10).collect::<Vec<_>>());
+
+		let path =
+			bfs([0], |&node| edges(&node).into_iter().map(|(next, _)| next), |&node| node == 4);
+		assert_eq!(path.map(|path| path.cost), Some(2));
+
+		let weights =
+			|&node: &u8| edges(&node).into_iter().map(|(next, cost)| (next, cost.min(1) as usize));
+		let path = zero_one_bfs([0], weights, |&node| node == 4).expect("path");
+		assert_eq!((path.cost, path.states.len()), (1, 3));
+	}
+
+	#[test]
+	fn zero_cost_cycles() {
+		// 0 and 1 as well as 1 and 3 are connected both ways at no cost.
+		let edges = |node: &u8| match node {
+			0 => vec![(1, 0)],
+			1 => vec![(0, 0), (3, 0), (2, 1)],
+			3 => vec![(1, 0), (2, 1)],
+			_ => vec![],
+		};
+		let all = dijkstra_all([0], edges, |&node| node == 2).expect("paths");
+		let mut paths = all.paths();
+		paths.sort();
+		assert_eq!(paths, [vec![0, 1, 2], vec![0, 1, 3, 2]]);
+		assert_eq!(all.states().len(), 4);
+
+		let path = zero_one_bfs([0], edges, |&node| node == 2).expect("path");
+		assert_eq!(path, Path { cost: 1, states: vec![0, 1, 2] });
+	}
+}
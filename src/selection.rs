@@ -0,0 +1,96 @@
+use std::{collections::BTreeSet, ops::RangeInclusive, str::FromStr};
+
+use anyhow::{bail, ensure, Context, Result};
+
+/// Range of days an advent calendar has.
+const CALENDAR: RangeInclusive<u8> = 1..=25;
+/// Year of the first advent calendar.
+const FIRST_YEAR: u16 = 2015;
+/// Year used when none is given.
+pub const DEFAULT_YEAR: u16 = 2023;
+
+/// Selection of days to run, parsed from the command line.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub enum DaySelection {
+	/// All implemented days.
+	All,
+	/// Explicitly listed days.
+	Days(BTreeSet<u8>),
+}
+
+impl DaySelection {
+	/// The explicitly listed days, if any.
+	pub fn explicit(&self) -> Option<&BTreeSet<u8>> {
+		match self {
+			Self::All => None,
+			Self::Days(days) => Some(days),
+		}
+	}
+}
+
+impl FromStr for DaySelection {
+	type Err = anyhow::Error;
+
+	fn from_str(s: &str) -> Result<Self> {
+		if s.trim() == "all" {
+			return Ok(Self::All);
+		}
+
+		let mut days = BTreeSet::new();
+		for item in s.split(',').map(str::trim) {
+			if let Some((start, end)) = item.split_once("..=") {
+				days.extend(parse_day(start)?..=parse_day(end)?);
+			} else if let Some((start, end)) = item.split_once("..") {
+				days.extend(parse_day(start)?..parse_day(end)?);
+			} else {
+				days.insert(parse_day(item)?);
+			}
+		}
+
+		if days.is_empty() {
+			bail!("`{s}` does not select any day");
+		}
+		Ok(Self::Days(days))
+	}
+}
+
+fn parse_day(day: &str) -> Result<u8> {
+	let day = day.trim().parse::<u8>().context(format!("`{day}` is not a valid day"))?;
+	ensure!(CALENDAR.contains(&day), "Day {day} is not in the range 1..=25");
+	Ok(day)
+}
+
+/// Parse a year on the command line, which must have an advent calendar.
+pub fn parse_year(year: &str) -> Result<u16> {
+	let year = year.trim().parse::<u16>().context(format!("`{year}` is not a valid year"))?;
+	ensure!(year >= FIRST_YEAR, "There is no advent calendar before {FIRST_YEAR}");
+	Ok(year)
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn parse() -> Result<()> {
+		assert_eq!("all".parse::<DaySelection>()?, DaySelection::All);
+		assert_eq!("5".parse::<DaySelection>()?, DaySelection::Days([5].into()));
+		assert_eq!("1,5,7".parse::<DaySelection>()?, DaySelection::Days([1, 5, 7].into()));
+		assert_eq!(
+			"3,10..=12".parse::<DaySelection>()?,
+			DaySelection::Days([3, 10, 11, 12].into())
+		);
+		assert_eq!("1..3".parse::<DaySelection>()?, DaySelection::Days([1, 2].into()));
+
+		assert!("0".parse::<DaySelection>().is_err());
+		assert!("26".parse::<DaySelection>().is_err());
+		assert!("1,x".parse::<DaySelection>().is_err());
+		assert!("5..5".parse::<DaySelection>().is_err());
+
+		assert_eq!(parse_year("2022")?, 2022);
+		assert!(parse_year("2014").is_err());
+		assert!(parse_year("23").is_err());
+
+		Ok(())
+	}
+}
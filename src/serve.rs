@@ -0,0 +1,477 @@
+use std::{
+	io::{self, BufRead, BufReader, ErrorKind, Read, Write},
+	net::{Shutdown, SocketAddr, TcpListener, TcpStream},
+	sync::{
+		atomic::{AtomicUsize, Ordering},
+		mpsc::{self, RecvTimeoutError},
+		Arc,
+	},
+	time::{Duration, Instant},
+};
+
+use anyhow::{ensure, Context, Result};
+use serde_json::{json, Value};
+
+use crate::{
+	api, crash,
+	days::{Puzzle, RunContext},
+	parse::ParseError,
+	report,
+	runner::Part,
+};
+
+/// Limits of the service.
+#[derive(Debug, Clone, Copy)]
+pub struct ServeOptions {
+	/// Largest accepted input in bytes.
+	pub max_input_size: usize,
+	/// Give up on a request after waiting this long for its input, and again
+	/// after parsing and solving it for this long.
+	pub timeout: Duration,
+	/// Largest number of inputs solved at once, counting timed out ones that
+	/// are still running. Requests beyond that are rejected.
+	pub max_solves: usize,
+}
+
+/// Status code and JSON body of a response.
+#[derive(Debug, PartialEq)]
+pub struct Reply {
+	pub status: u16,
+	pub body: Value,
+}
+
+impl Reply {
+	fn ok(body: Value) -> Self {
+		Self { status: 200, body }
+	}
+
+	fn error(status: u16, message: impl std::fmt::Display) -> Self {
+		Self { status, body: json!({ "error": message.to_string() }) }
+	}
+}
+
+/// Serve the API on the loopback address until the process is stopped:
+///
+/// - `GET /days` lists the implemented days.
+/// - `POST /{year}/{day}/{part}` solves the part on the input in the body.
+///
+/// Each connection carries one request. Requests are handled by one thread
+/// more than the number of solves allowed at once. A solve is taken before
+/// reading the input, so there is always one thread left to reject requests
+/// with `503` when all solves are taken. Requests that do not arrive in time
+/// are rejected with `408`.
+///
+/// A timed out part is cancelled, but keeps running and holding its solve
+/// until it notices. A day that never checks for cancellation holds it until
+/// the part is done, so enough such requests leave only `503` replies.
+pub fn serve(address: SocketAddr, options: ServeOptions) -> Result<()> {
+	ensure!(address.ip().is_loopback(), "Can only serve on a loopback address like 127.0.0.1");
+	let listener =
+		TcpListener::bind(address).with_context(|| format!("Could not listen on {address}"))?;
+	let address = listener.local_addr().unwrap_or(address);
+	println!("Listening on http://{address}, press Ctrl-C to stop.");
+
+	let service = Service::new(options);
+	std::thread::scope(|scope| {
+		for _ in 0..=options.max_solves {
+			scope.spawn(|| {
+				// A failed connection only concerns its client.
+				for stream in listener.incoming().flatten() {
+					service.respond(stream);
+				}
+			});
+		}
+	});
+	Ok(())
+}
+
+/// The API with the solves running at the moment.
+#[derive(Debug)]
+pub struct Service {
+	options: ServeOptions,
+	solves: Arc<AtomicUsize>,
+}
+
+/// Solve taken from a [`Service`], given back when dropped.
+struct SolveSlot(Arc<AtomicUsize>);
+
+impl Drop for SolveSlot {
+	fn drop(&mut self) {
+		self.0.fetch_sub(1, Ordering::Relaxed);
+	}
+}
+
+impl Service {
+	pub fn new(options: ServeOptions) -> Self {
+		Self { options, solves: Arc::default() }
+	}
+
+	/// Take a solve, `None` if all are taken.
+	fn take_slot(&self) -> Option<SolveSlot> {
+		self.solves
+			.fetch_update(Ordering::Relaxed, Ordering::Relaxed, |solves| {
+				(solves < self.options.max_solves).then_some(solves + 1)
+			})
+			.ok()
+			.map(|_| SolveSlot(Arc::clone(&self.solves)))
+	}
+
+	/// Read the request of a connection and reply to it.
+	fn respond(&self, stream: TcpStream) {
+		let options = self.options;
+		// A client that does not read its reply gives up its thread in time.
+		let _ = stream.set_write_timeout(Some(options.timeout));
+		let deadline = Instant::now() + options.timeout;
+		let mut reader = BufReader::new(Arrival { stream: &stream, deadline });
+		let reply = match read_head(&mut reader, options) {
+			Ok(Head { chunked: true, .. }) => {
+				Reply::error(411, "Send the input with a Content-Length header")
+			}
+			Ok(Head { content_length: Some(length), .. }) if length > options.max_input_size => {
+				too_large(options)
+			}
+			Ok(head) => {
+				if head.expect_continue {
+					let _ = (&stream).write_all(b"HTTP/1.1 100 Continue\r\n\r\n");
+				}
+				let body = Read::take(&mut reader, head.content_length.unwrap_or(0) as u64);
+				self.handle(&head.method, &head.url, body)
+			}
+			Err(reply) => reply,
+		};
+		// The client may be gone, there is no one to tell.
+		let _ = write_reply(&stream, &reply);
+		// Closing with unread input would reset the connection and lose the
+		// reply, so give the client a moment to close it first.
+		let _ = stream.shutdown(Shutdown::Write);
+		let arrival = reader.get_mut();
+		arrival.deadline = arrival.deadline.min(Instant::now() + CLOSE_GRACE);
+		let _ = io::copy(&mut reader, &mut io::sink());
+	}
+
+	/// Answer a request.
+	pub fn handle(&self, method: &str, url: &str, body: impl Read) -> Reply {
+		let options = self.options;
+		let path = url.split('?').next().unwrap_or_default();
+		let segments = path.split('/').filter(|segment| !segment.is_empty()).collect::<Vec<_>>();
+		match (method, segments.as_slice()) {
+			("GET", ["days"]) => {
+				let days = api::implemented_days()
+					.map(|(year, day)| json!({ "year": year, "day": day }))
+					.collect::<Vec<_>>();
+				Reply::ok(json!({ "days": days }))
+			}
+			("POST", [year, day, part]) => {
+				let (Ok(year), Ok(day), Some(part)) = (year.parse(), day.parse(), parse_part(part))
+				else {
+					return Reply::error(404, format!("No such day or part: {path}"));
+				};
+				let Some(puzzle) = api::puzzle(year, day) else {
+					return Reply::error(404, format!("Day {day} of {year} is not implemented"));
+				};
+				let Some(slot) = self.take_slot() else {
+					return Reply::error(503, "Too many inputs are being solved, try again later");
+				};
+				let input = match read_input(body, options) {
+					Ok(input) => input,
+					Err(reply) => return reply,
+				};
+				solve(puzzle, (year, day, part), input, options, slot)
+			}
+			(_, ["days"] | [_, _, _]) => Reply::error(405, format!("Method {method} not allowed")),
+			_ => Reply::error(404, format!("No such endpoint: {path}")),
+		}
+	}
+}
+
+fn parse_part(part: &str) -> Option<Part> {
+	match part {
+		"1" => Some(Part::One),
+		"2" => Some(Part::Two),
+		_ => None,
+	}
+}
+
+fn too_large(options: ServeOptions) -> Reply {
+	Reply::error(413, format!("Input is larger than {} bytes", options.max_input_size))
+}
+
+/// Reads of a connection, which fail once its request took too long to
+/// arrive.
+struct Arrival<'a> {
+	stream: &'a TcpStream,
+	deadline: Instant,
+}
+
+impl Read for Arrival<'_> {
+	fn read(&mut self, buffer: &mut [u8]) -> io::Result<usize> {
+		let left = self.deadline.saturating_duration_since(Instant::now());
+		if left.is_zero() {
+			return Err(ErrorKind::TimedOut.into());
+		}
+		self.stream.set_read_timeout(Some(left))?;
+		self.stream.read(buffer)
+	}
+}
+
+/// How long to wait for a client to close its connection after the reply.
+const CLOSE_GRACE: Duration = Duration::from_millis(100);
+
+/// Largest accepted request line and headers in bytes.
+const MAX_HEAD_SIZE: u64 = 8192;
+
+/// Request line and the headers of a request that matter here.
+#[derive(Debug, Default)]
+struct Head {
+	method: String,
+	url: String,
+	content_length: Option<usize>,
+	/// Whether the body has a transfer encoding, which is not supported.
+	chunked: bool,
+	/// Whether the client waits for a go-ahead before sending the body.
+	expect_continue: bool,
+}
+
+fn read_head(reader: impl BufRead, options: ServeOptions) -> Result<Head, Reply> {
+	let mut reader = reader.take(MAX_HEAD_SIZE);
+	let mut next_line = || {
+		let mut line = String::new();
+		reader.read_line(&mut line).map_err(|err| read_failed(&err, options))?;
+		match line.strip_suffix('\n') {
+			Some(line) => Ok(line.trim_end_matches('\r').to_owned()),
+			None if reader.limit() == 0 => Err(Reply::error(431, "The headers are too large")),
+			None => Err(Reply::error(400, "The request ended early")),
+		}
+	};
+
+	let line = next_line()?;
+	let [method, url, _version] = line.split(' ').collect::<Vec<_>>()[..] else {
+		return Err(Reply::error(400, format!("Malformed request line: {line}")));
+	};
+	let mut head = Head { method: method.to_owned(), url: url.to_owned(), ..Head::default() };
+	loop {
+		let line = next_line()?;
+		if line.is_empty() {
+			return Ok(head);
+		}
+		let Some((name, value)) = line.split_once(':') else {
+			return Err(Reply::error(400, format!("Malformed header: {line}")));
+		};
+		let value = value.trim();
+		match name.to_ascii_lowercase().as_str() {
+			"content-length" => {
+				let length = value
+					.parse()
+					.map_err(|_| Reply::error(400, format!("Malformed Content-Length: {value}")))?;
+				head.content_length = Some(length);
+			}
+			"transfer-encoding" => head.chunked = true,
+			"expect" => head.expect_continue = value.eq_ignore_ascii_case("100-continue"),
+			_ => {}
+		}
+	}
+}
+
+fn read_failed(err: &io::Error, options: ServeOptions) -> Reply {
+	match err.kind() {
+		ErrorKind::WouldBlock | ErrorKind::TimedOut => {
+			Reply::error(408, format!("The request did not arrive within {:.2?}", options.timeout))
+		}
+		_ => Reply::error(400, format!("Could not read the request: {err}")),
+	}
+}
+
+/// Read the input, failing if it is too large, not UTF-8 or does not arrive
+/// in time.
+fn read_input(body: impl Read, options: ServeOptions) -> Result<String, Reply> {
+	let mut bytes = Vec::new();
+	let limit = options.max_input_size as u64 + 1;
+	body.take(limit).read_to_end(&mut bytes).map_err(|err| read_failed(&err, options))?;
+	if bytes.len() > options.max_input_size {
+		return Err(too_large(options));
+	}
+	String::from_utf8(bytes).map_err(|_| Reply::error(400, "Input is not UTF-8"))
+}
+
+fn write_reply(mut stream: &TcpStream, reply: &Reply) -> io::Result<()> {
+	let reason = match reply.status {
+		200 => "OK",
+		400 => "Bad Request",
+		404 => "Not Found",
+		405 => "Method Not Allowed",
+		408 => "Request Timeout",
+		411 => "Length Required",
+		413 => "Content Too Large",
+		422 => "Unprocessable Content",
+		431 => "Request Header Fields Too Large",
+		503 => "Service Unavailable",
+		504 => "Gateway Timeout",
+		_ => "Internal Server Error",
+	};
+	let body = reply.body.to_string();
+	let response = format!(
+		"HTTP/1.1 {} {reason}\r\nContent-Type: application/json\r\nContent-Length: {}\r\n\
+		 Connection: close\r\n\r\n{body}",
+		reply.status,
+		body.len(),
+	);
+	stream.write_all(response.as_bytes())
+}
+
+/// Parse and solve on another thread, so the request can give up on it. The
+/// thread holds the slot until it is done.
+fn solve(
+	puzzle: &'static dyn Puzzle,
+	(year, day, part): (u16, u8, Part),
+	input: String,
+	options: ServeOptions,
+	slot: SolveSlot,
+) -> Reply {
+	let ctx = RunContext::default();
+	let cancel = ctx.cancel_flag();
+	let (sender, receiver) = mpsc::channel();
+	std::thread::spawn(move || {
+		let time = Instant::now();
+		let parsed = crash::catch(|| puzzle.parse(&input));
+		let parse_duration = time.elapsed();
+		let reply = match parsed {
+			Ok(parsed) => {
+				let time = Instant::now();
+				match crash::catch(|| puzzle.solve(part, &parsed, &ctx)) {
+					Ok(answer) => Reply::ok(json!({
+						"year": year,
+						"day": day,
+						"part": part.number(),
+						"answer": report::answer_json(&answer),
+						"answer_kind": answer.kind(),
+						"parse_duration_ns": report::nanos(parse_duration),
+						"duration_ns": report::nanos(time.elapsed()),
+					})),
+					Err(err) => failed(&err),
+				}
+			}
+			Err(err) => failed(&err),
+		};
+		// Give the slot back first, the request may be followed by another.
+		drop(slot);
+		// The request is gone if it timed out.
+		let _ = sender.send(reply);
+	});
+
+	match receiver.recv_timeout(options.timeout) {
+		Ok(reply) => reply,
+		Err(RecvTimeoutError::Timeout) => {
+			cancel.store(true, Ordering::Relaxed);
+			Reply::error(504, format!("Timed out after {:.2?}", options.timeout))
+		}
+		Err(RecvTimeoutError::Disconnected) => Reply::error(500, "The solver stopped"),
+	}
+}
+
+/// Reply to a failed parse or part. Rejected inputs are the client's fault
+/// and carry the location of the error if known, days may only find them
+/// while solving.
+fn failed(err: &anyhow::Error) -> Reply {
+	let Some(parse_error) = err.downcast_ref::<ParseError>() else {
+		return Reply::error(500, format!("{err:#}"));
+	};
+	let mut reply = Reply::error(422, format!("{err:#}"));
+	if let Some(location) = parse_error.location() {
+		reply.body["line"] = location.line.into();
+		reply.body["column"] = location.columns.start.into();
+	}
+	reply
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	const OPTIONS: ServeOptions =
+		ServeOptions { max_input_size: 64, timeout: Duration::from_secs(10), max_solves: 1 };
+
+	/// Body of a client that stopped sending.
+	struct Stalled;
+
+	impl Read for Stalled {
+		fn read(&mut self, _buffer: &mut [u8]) -> std::io::Result<usize> {
+			Err(ErrorKind::WouldBlock.into())
+		}
+	}
+
+	#[test]
+	fn requests() {
+		let service = Service::new(OPTIONS);
+		let reply = service.handle("GET", "/days", std::io::empty());
+		assert_eq!(reply.status, 200);
+		assert!(reply.body["days"]
+			.as_array()
+			.expect("list of days")
+			.contains(&json!({ "year": 2023, "day": 6 })));
+
+		let input = "Time:      7  15   30\nDistance:  9  40  200\n";
+		let reply = service.handle("POST", "/2023/6/1", input.as_bytes());
+		assert_eq!(reply.status, 200);
+		assert_eq!(reply.body["answer"], 288);
+		assert_eq!(reply.body["answer_kind"], "int");
+		assert!(reply.body["duration_ns"].is_u64());
+
+		let reply = service.handle("POST", "/2023/6/2", "Time: 7\nDistance: x".as_bytes());
+		assert_eq!(reply.status, 422);
+		assert_eq!((&reply.body["line"], &reply.body["column"]), (&json!(2), &json!(10)));
+
+		let large = "1".repeat(65);
+		let reply = service.handle("POST", "/2023/6/1", large.as_bytes());
+		assert_eq!(reply.status, 413);
+
+		assert_eq!(service.handle("POST", "/2023/6/3", std::io::empty()).status, 404);
+		assert_eq!(service.handle("POST", "/2023/25/1", std::io::empty()).status, 404);
+		assert_eq!(service.handle("GET", "/2023/6/1", std::io::empty()).status, 405);
+
+		let slot = service.take_slot();
+		let reply = service.handle("POST", "/2023/6/1", input.as_bytes());
+		assert_eq!(reply.status, 503);
+		drop(slot);
+		assert_eq!(service.handle("POST", "/2023/6/1", input.as_bytes()).status, 200);
+
+		assert_eq!(service.handle("POST", "/2023/6/1", Stalled).status, 408);
+		assert_eq!(service.handle("POST", "/2023/6/1", input.as_bytes()).status, 200);
+	}
+
+	#[test]
+	fn connections() -> Result<()> {
+		let options = ServeOptions { timeout: Duration::from_millis(200), ..OPTIONS };
+		let service = Service::new(options);
+		let listener = TcpListener::bind("127.0.0.1:0")?;
+		let address = listener.local_addr()?;
+		let send = |request: &str| -> Result<String> {
+			let mut stream = TcpStream::connect(address)?;
+			stream.write_all(request.as_bytes())?;
+			let mut reply = String::new();
+			stream.read_to_string(&mut reply)?;
+			Ok(reply)
+		};
+		std::thread::scope(|scope| {
+			scope.spawn(|| {
+				for stream in listener.incoming().take(4).flatten() {
+					service.respond(stream);
+				}
+			});
+
+			let reply = send("GET /days HTTP/1.1\r\nHost: localhost\r\n\r\n")?;
+			assert!(reply.starts_with("HTTP/1.1 200 OK\r\n"), "{reply}");
+			assert!(reply.ends_with("]}"), "{reply}");
+
+			let input = "Time: 7\nDistance: 9\n";
+			let request = format!("POST /2023/6/1 HTTP/1.1\r\nContent-Length: 20\r\n\r\n{input}");
+			let reply = send(&request)?;
+			assert!(reply.contains("\"answer\":4"), "{reply}");
+
+			let reply = send("GET /days HTTP/1.1\r\n")?;
+			assert!(reply.starts_with("HTTP/1.1 408 Request Timeout\r\n"), "{reply}");
+			let reply = send("POST /2023/6/1 HTTP/1.1\r\nContent-Length: 20\r\n\r\nTime")?;
+			assert!(reply.starts_with("HTTP/1.1 408 Request Timeout\r\n"), "{reply}");
+			Ok(())
+		})
+	}
+}
@@ -0,0 +1,160 @@
+use std::{
+	collections::{BTreeMap, BTreeSet},
+	fmt::Write,
+	path::{Path, PathBuf},
+	time::{Duration, SystemTime},
+};
+
+use anyhow::{ensure, Result};
+
+use crate::{
+	report::{self, DayReport, Outcome},
+	runner::{self, InputSets, Part, RunOptions},
+	selection::DaySelection,
+};
+
+/// Time between checks of the watched files.
+const POLL_INTERVAL: Duration = Duration::from_millis(300);
+
+/// Displayed result of each selected part, to compare consecutive runs.
+type Results = BTreeMap<(u8, Part), String>;
+
+/// Modification time and size of a file, `None` if it does not exist.
+type Stamp = Option<(SystemTime, u64)>;
+
+/// Run the selected days, then re-run a day whenever its input file changes
+/// and print how its answers changed. Runs until the process is stopped.
+pub fn watch(year: u16, selection: &DaySelection, options: &RunOptions) -> Result<()> {
+	ensure!(options.input.as_deref() != Some(Path::new("-")), "Cannot watch stdin");
+	let files = runner::selected_days(year, selection)
+		.into_iter()
+		.map(|day| {
+			let path = match (&options.input, &options.input_sets) {
+				(Some(input), _) => input.clone(),
+				(None, InputSets::Named(name)) => runner::input_set_path(year, day, name),
+				(None, _) => runner::input_path(year, day).into(),
+			};
+			(day, path)
+		})
+		.collect::<BTreeMap<u8, PathBuf>>();
+	let parts = [Part::One, Part::Two]
+		.into_iter()
+		.filter(|part| options.part.is_none_or(|only| only == *part))
+		.collect::<Vec<_>>();
+
+	let reports = runner::run_days(year, selection, options)?;
+	print!("{}", report::summary_table(&reports));
+	let mut previous = results(&reports, &parts);
+	let mut stamps = files.values().map(|path| stamp(path)).collect::<Vec<_>>();
+	println!("\nWatching {} input file(s) for changes, press Ctrl-C to stop.", files.len());
+
+	loop {
+		std::thread::sleep(POLL_INTERVAL);
+		let current = files.values().map(|path| stamp(path)).collect::<Vec<_>>();
+		let changed = files
+			.keys()
+			.zip(stamps.iter().zip(&current))
+			.filter(|(_, (before, now))| before != now)
+			.map(|(day, _)| *day)
+			.collect::<BTreeSet<_>>();
+		stamps = current;
+		if changed.is_empty() {
+			continue;
+		}
+
+		let days = changed.iter().map(u8::to_string).collect::<Vec<_>>().join(", ");
+		println!("\nInput of day {days} changed:");
+		match runner::run_days(year, &DaySelection::Days(changed), options) {
+			Ok(reports) => {
+				let results = results(&reports, &parts);
+				print!("{}", diff(&previous, &results, &reports));
+				previous.extend(results);
+			}
+			Err(err) => println!("Error: {err:#}"),
+		}
+	}
+}
+
+fn stamp(path: &Path) -> Stamp {
+	let metadata = std::fs::metadata(path).ok()?;
+	Some((metadata.modified().ok()?, metadata.len()))
+}
+
+/// Result text of each selected part of the reports.
+fn results(reports: &[DayReport], parts: &[Part]) -> Results {
+	let mut results = Results::new();
+	for report in reports {
+		let day_result = match &report.outcome {
+			Outcome::Skipped(reason) => format!("skipped ({reason})"),
+			Outcome::ParseFailed { error, .. } => format!("parse error: {error:#}"),
+			Outcome::Solved { .. } => {
+				for (part, part_report) in report.parts() {
+					let result = match &part_report.answer {
+						Ok(answer) => answer.to_string(),
+						Err(err) => format!("error: {err:#}"),
+					};
+					results.insert((report.day, part), result);
+				}
+				continue;
+			}
+		};
+		for part in parts {
+			results.insert((report.day, *part), day_result.clone());
+		}
+	}
+	results
+}
+
+/// One line per part comparing the current result with the previous one.
+fn diff(previous: &Results, current: &Results, reports: &[DayReport]) -> String {
+	let durations = reports
+		.iter()
+		.flat_map(|report| {
+			report.parts().map(|(part, part_report)| ((report.day, part), part_report.duration))
+		})
+		.collect::<BTreeMap<_, _>>();
+
+	let mut diff = String::new();
+	for (key @ (day, part), result) in current {
+		let time =
+			durations.get(key).map(|duration| format!(", {duration:.2?}")).unwrap_or_default();
+		let line = match previous.get(key) {
+			Some(before) if before == result => format!("{} (unchanged{time})", compact(result)),
+			Some(before) => format!("{} -> {} (changed{time})", compact(before), compact(result)),
+			None => format!("{} (new{time})", compact(result)),
+		};
+		writeln!(diff, "  Day {day} part {part}: {line}").expect("writing to String");
+	}
+	diff
+}
+
+/// Result text on a single line, multi-line answers are only counted.
+fn compact(result: &str) -> String {
+	match result.lines().count() {
+		0 | 1 => result.to_owned(),
+		lines => format!("({lines} lines)"),
+	}
+}
+
+#[cfg(test)]
+mod tests {
+	use super::*;
+
+	#[test]
+	fn diffs() {
+		let previous = Results::from([
+			((6, Part::One), "288".to_owned()),
+			((6, Part::Two), "71503".to_owned()),
+		]);
+		let current = Results::from([
+			((6, Part::One), "288".to_owned()),
+			((6, Part::Two), "71504".to_owned()),
+			((7, Part::One), "#.\n.#".to_owned()),
+		]);
+		assert_eq!(
+			diff(&previous, &current, &[]),
+			"  Day 6 part 1: 288 (unchanged)\n  Day 6 part 2: 71503 -> 71504 (changed)\n  Day 7 \
+			 part 1: (2 lines) (new)\n"
+		);
+	}
+}
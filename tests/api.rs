@@ -0,0 +1,31 @@
+use advent_of_code_2023::{implemented_days, puzzle, solve, Answer, Part, RunContext, SolveError};
+
+const DAY_6: &str = "Time:      7  15   30\nDistance:  9  40  200\n";
+
+#[test]
+fn solve_days() -> Result<(), SolveError> {
+	assert!(implemented_days().any(|day| day == (2023, 6)));
+	assert_eq!(solve(2023, 6, Part::One, DAY_6)?, Answer::Int(288));
+	assert_eq!(solve(2023, 6, Part::Two, DAY_6)?, Answer::Int(71503));
+
+	let day = puzzle(2023, 6).expect("day 6 is implemented");
+	let parsed = day.parse(DAY_6).expect("parsing the example");
+	assert_eq!(
+		day.part1(&parsed, &RunContext::default()).expect("solving part 1"),
+		Answer::Int(288)
+	);
+	Ok(())
+}
+
+#[test]
+fn errors() {
+	assert!(puzzle(2023, 25).is_none());
+	assert!(puzzle(2015, 6).is_none());
+	assert!(matches!(
+		solve(2023, 25, Part::One, ""),
+		Err(SolveError::NotImplemented { year: 2023, day: 25 })
+	));
+	let error = solve(2023, 6, Part::One, "Time: x").expect_err("malformed input");
+	assert!(matches!(error, SolveError::Parse { year: 2023, day: 6, .. }));
+	assert_eq!(error.to_string(), "Could not parse the input of day 6 of 2023");
+}